@@ -1,6 +1,8 @@
 use bevy_ecs::component::Component;
 use bevy_ecs::entity::Entity;
 use bevy_ecs::world::World;
+#[cfg(feature = "nav-traits")]
+use bevy_entity_ptr::PtrArena;
 use bevy_entity_ptr::{BoundEntity, EntityHandle, EntityPtr, WorldExt};
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
 
@@ -13,6 +15,13 @@ struct Value(i32);
 #[derive(Component)]
 struct Children(Vec<EntityHandle>);
 
+#[cfg(feature = "nav-traits")]
+impl bevy_entity_ptr::HasChildren for Children {
+    fn children_handles(&self) -> &[EntityHandle] {
+        &self.0
+    }
+}
+
 /// Build a linear chain of `depth` entities, each pointing to the next via Parent.
 /// Returns the first entity in the chain.
 fn build_chain(world: &mut World, depth: usize) -> Entity {
@@ -102,7 +111,7 @@ fn sum_tree_bound(bound: BoundEntity) -> i32 {
         .get::<Children>()
         .map(|c| {
             c.0.iter()
-                .map(|h| sum_tree_bound(h.bind(bound.world())))
+                .map(|&h| sum_tree_bound(bound.follow_handle(h)))
                 .sum()
         })
         .unwrap_or(0);
@@ -118,6 +127,20 @@ fn sum_tree_raw(world: &World, entity: Entity) -> i32 {
     mine + children_sum
 }
 
+#[cfg(feature = "nav-traits")]
+fn sum_tree_children_slice(ptr: EntityPtr) -> i32 {
+    let mine = ptr.get::<Value>().map(|v| v.0).unwrap_or(0);
+    let children_sum: i32 = ptr
+        .children_slice::<Children>()
+        .map(|s| {
+            s.iter()
+                .map(|&h| sum_tree_children_slice(ptr.follow_handle(h)))
+                .sum()
+        })
+        .unwrap_or(0);
+    mine + children_sum
+}
+
 // =========================================================================
 // Benchmarks
 // =========================================================================
@@ -170,5 +193,85 @@ fn bench_tree_sum(c: &mut Criterion) {
     group.finish();
 }
 
+// =========================================================================
+// Recursive tree sum via children_slice (requires nav-traits)
+// =========================================================================
+
+#[cfg(feature = "nav-traits")]
+fn bench_tree_sum_children_slice(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tree_sum");
+
+    for depth in [4, 6, 8, 10] {
+        let mut world = World::new();
+        let root = build_tree(&mut world, depth);
+        let node_count = (1 << (depth + 1)) - 1;
+
+        group.bench_function(
+            format!("children_slice_depth_{}_nodes_{}", depth, node_count),
+            |b| b.iter(|| sum_tree_children_slice(world.entity_ptr(black_box(root)))),
+        );
+    }
+
+    group.finish();
+}
+
+// =========================================================================
+// Descendant collection — fresh Vec vs reused buffer vs PtrArena (requires nav-traits)
+// =========================================================================
+
+#[cfg(feature = "nav-traits")]
+fn bench_descendant_collection(c: &mut Criterion) {
+    let mut group = c.benchmark_group("descendant_collection");
+
+    let depth = 8;
+    let mut world = World::new();
+    let root = build_tree(&mut world, depth);
+
+    group.bench_function(format!("collect_depth_{}", depth), |b| {
+        b.iter(|| {
+            let all: Vec<EntityPtr> = world
+                .entity_ptr(black_box(root))
+                .nav_many()
+                .descendants::<Children>()
+                .collect();
+            all.len()
+        })
+    });
+
+    let mut buffer = Vec::new();
+    group.bench_function(format!("extend_reused_depth_{}", depth), |b| {
+        b.iter(|| {
+            buffer.clear();
+            world
+                .entity_ptr(black_box(root))
+                .nav_many()
+                .extend_descendants::<Children>(&mut buffer);
+            buffer.len()
+        })
+    });
+
+    let mut arena = PtrArena::new();
+    group.bench_function(format!("arena_depth_{}", depth), |b| {
+        b.iter(|| {
+            arena
+                .descendants::<Children>(world.entity_ptr(black_box(root)))
+                .len()
+        })
+    });
+
+    group.finish();
+}
+
 criterion_group!(benches, bench_chain_traversal, bench_tree_sum);
+
+#[cfg(feature = "nav-traits")]
+criterion_group!(
+    nav_benches,
+    bench_tree_sum_children_slice,
+    bench_descendant_collection
+);
+
+#[cfg(not(feature = "nav-traits"))]
 criterion_main!(benches);
+#[cfg(feature = "nav-traits")]
+criterion_main!(benches, nav_benches);
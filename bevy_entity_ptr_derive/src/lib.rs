@@ -0,0 +1,97 @@
+//! Derive macros for `bevy_entity_ptr`'s `HasChildren` and `HasParent` traits.
+//!
+//! Use through `bevy_entity_ptr` with the `derive` feature enabled rather than
+//! depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Member, parse_macro_input};
+
+/// Derives `HasChildren` from a `Vec<EntityHandle>` field.
+///
+/// Mark the field with `#[children]`. The attribute may be omitted when the
+/// struct has exactly one field.
+#[proc_macro_derive(HasChildren, attributes(children))]
+pub fn derive_has_children(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, "children", quote!(HasChildren), |member| {
+        quote! {
+            fn children_handles(&self) -> &[::bevy_entity_ptr::EntityHandle] {
+                &self.#member
+            }
+        }
+    })
+    .unwrap_or_else(syn::Error::into_compile_error)
+    .into()
+}
+
+/// Derives `HasParent` from an `EntityHandle` or `Option<EntityHandle>` field.
+///
+/// Mark the field with `#[parent]`. The attribute may be omitted when the
+/// struct has exactly one field.
+#[proc_macro_derive(HasParent, attributes(parent))]
+pub fn derive_has_parent(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, "parent", quote!(HasParent), |member| {
+        // `Option<T>: From<T>` plus the identity `From` cover both field shapes.
+        quote! {
+            fn parent_handle(&self) -> ::core::option::Option<::bevy_entity_ptr::EntityHandle> {
+                ::core::convert::From::from(self.#member)
+            }
+        }
+    })
+    .unwrap_or_else(syn::Error::into_compile_error)
+    .into()
+}
+
+/// Generates `impl bevy_entity_ptr::<trait_name> for <Type>` around the method built by `body`.
+fn expand(
+    input: &DeriveInput,
+    attr: &str,
+    trait_name: TokenStream2,
+    body: impl FnOnce(Member) -> TokenStream2,
+) -> syn::Result<TokenStream2> {
+    let member = find_field(input, attr)?;
+    let method = body(member);
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::bevy_entity_ptr::#trait_name for #ident #ty_generics #where_clause {
+            #method
+        }
+    })
+}
+
+/// Finds the field marked `#[attr]`, or the only field if none is marked.
+fn find_field(input: &DeriveInput, attr: &str) -> syn::Result<Member> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                format!("`#[{attr}]` derives only support structs"),
+            ));
+        }
+    };
+
+    let members = fields.members();
+    let mut marked = fields
+        .iter()
+        .zip(members)
+        .filter(|(field, _)| field.attrs.iter().any(|a| a.path().is_ident(attr)));
+
+    match (marked.next(), marked.next()) {
+        (Some((_, member)), None) => Ok(member),
+        (Some(_), Some((field, _))) => Err(syn::Error::new_spanned(
+            field,
+            format!("only one field may be marked `#[{attr}]`"),
+        )),
+        (None, _) if fields.len() == 1 => Ok(fields.members().next().unwrap()),
+        (None, _) => Err(syn::Error::new_spanned(
+            &input.ident,
+            format!("mark the handle field with `#[{attr}]`"),
+        )),
+    }
+}
@@ -0,0 +1,123 @@
+//! Graph export example demonstrating generic edge discovery with `HandleContainer`.
+//!
+//! This example shows how to:
+//! - Implement `HandleContainer` for components that store entity references
+//! - Collect an entity's outgoing edges with `EntityPtr::outgoing_handles`
+//! - Combine several link components into one adjacency view
+//! - Export the entity graph as a simple edge list
+//!
+//! Run with: `cargo run --example graph_export`
+
+use bevy_ecs::prelude::*;
+use bevy_entity_ptr::{EntityHandle, EntityPtr, HandleContainer, WorldExt};
+
+#[derive(Component)]
+struct Name(&'static str);
+
+// A single mandatory link
+#[derive(Component)]
+struct ReportsTo(EntityHandle);
+
+// An optional link
+#[derive(Component)]
+struct Mentor(Option<EntityHandle>);
+
+// A multi-link
+#[derive(Component)]
+struct Collaborators(Vec<EntityHandle>);
+
+impl HandleContainer for ReportsTo {
+    fn for_each_handle(&self, f: impl FnMut(EntityHandle)) {
+        self.0.for_each_handle(f);
+    }
+}
+
+impl HandleContainer for Mentor {
+    fn for_each_handle(&self, f: impl FnMut(EntityHandle)) {
+        self.0.for_each_handle(f);
+    }
+}
+
+impl HandleContainer for Collaborators {
+    fn for_each_handle(&self, f: impl FnMut(EntityHandle)) {
+        self.0.for_each_handle(f);
+    }
+}
+
+fn name(ptr: EntityPtr) -> &'static str {
+    ptr.get::<Name>().map_or("?", |n| n.0)
+}
+
+// Collect labelled edges from every link component of one entity
+fn edges_of(ptr: EntityPtr) -> Vec<(&'static str, &'static str, &'static str)> {
+    let labelled = [
+        ("reports_to", ptr.outgoing_handles::<ReportsTo>()),
+        ("mentor", ptr.outgoing_handles::<Mentor>()),
+        ("collaborator", ptr.outgoing_handles::<Collaborators>()),
+    ];
+
+    labelled
+        .into_iter()
+        .flat_map(|(label, handles)| {
+            handles
+                .into_iter()
+                .map(move |h| (name(ptr), label, name(ptr.follow_handle(h))))
+        })
+        .collect()
+}
+
+fn main() {
+    let mut world = World::new();
+
+    // Build a small org chart:
+    //
+    //   ceo <-reports_to- cto <-reports_to- dev
+    //                      ^                 |
+    //                      +----mentor-------+
+    //   dev <-collaborator-> designer
+    //
+    let ceo = world.spawn(Name("ceo")).id();
+    let cto = world
+        .spawn((Name("cto"), ReportsTo(EntityHandle::new(ceo))))
+        .id();
+    let designer = world
+        .spawn((Name("designer"), ReportsTo(EntityHandle::new(cto))))
+        .id();
+    let dev = world
+        .spawn((
+            Name("dev"),
+            ReportsTo(EntityHandle::new(cto)),
+            Mentor(Some(EntityHandle::new(cto))),
+            Collaborators(vec![EntityHandle::new(designer)]),
+        ))
+        .id();
+    world
+        .entity_mut(designer)
+        .insert((Mentor(None), Collaborators(vec![EntityHandle::new(dev)])));
+
+    // Export every edge in the graph
+    let mut edge_list: Vec<_> = [ceo, cto, designer, dev]
+        .into_iter()
+        .flat_map(|e| edges_of(world.entity_ptr(e)))
+        .collect();
+    edge_list.sort_unstable();
+
+    println!("Edge list (from, label, to):");
+    for (from, label, to) in &edge_list {
+        println!("  {} -[{}]-> {}", from, label, to);
+    }
+
+    assert_eq!(
+        edge_list,
+        vec![
+            ("cto", "reports_to", "ceo"),
+            ("designer", "collaborator", "dev"),
+            ("designer", "reports_to", "cto"),
+            ("dev", "collaborator", "designer"),
+            ("dev", "mentor", "cto"),
+            ("dev", "reports_to", "cto"),
+        ]
+    );
+
+    println!("\nAll assertions passed!");
+}
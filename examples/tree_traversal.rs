@@ -8,6 +8,7 @@
 //! - Use `WorldExt` to create `EntityPtr` without unsafe blocks
 //!
 //! Run with: `cargo run --example tree_traversal`
+//! (add `--features nav-traits` to also run the `collect_field_preorder` variant)
 
 use bevy_ecs::prelude::*;
 use bevy_entity_ptr::{EntityHandle, EntityPtr, WorldExt};
@@ -26,6 +27,13 @@ struct Parent(EntityHandle);
 #[derive(Component)]
 struct Children(Vec<EntityHandle>);
 
+#[cfg(feature = "nav-traits")]
+impl bevy_entity_ptr::HasChildren for Children {
+    fn children_handles(&self) -> &[EntityHandle] {
+        &self.0
+    }
+}
+
 // Recursive function to sum all values in a subtree
 fn sum_tree(node: EntityPtr) -> i32 {
     let my_value = node.get::<Value>().map(|v| v.0).unwrap_or(0);
@@ -151,5 +159,15 @@ fn main() {
     println!("\nPre-order traversal: {:?}", names);
     assert_eq!(names, vec!["root", "a", "c", "d", "b"]);
 
+    // With `nav-traits`, the same collection is a single call
+    #[cfg(feature = "nav-traits")]
+    {
+        let nav_names = root_ptr
+            .nav_many()
+            .collect_field_preorder::<Children, Name, _, _>(|n| n.0);
+        println!("Pre-order via collect_field_preorder: {:?}", nav_names);
+        assert_eq!(nav_names, names);
+    }
+
     println!("\nAll assertions passed!");
 }
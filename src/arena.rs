@@ -0,0 +1,149 @@
+//! Reusable storage for per-frame traversal results.
+
+use bevy_ecs::component::Component;
+
+use crate::nav::HasChildren;
+use crate::ptr::EntityPtr;
+
+/// A reusable buffer for collecting `EntityPtr`s without allocating on every call.
+///
+/// Systems that collect many temporary subtrees per frame can keep one arena and
+/// route collections through it: after the first few calls the backing `Vec` has
+/// grown to fit and later calls do not allocate.
+///
+/// # Borrow Semantics
+///
+/// Each collecting method clears the arena and returns a slice borrowed from it.
+/// The slice is valid until the next call that takes `&mut self`, which the borrow
+/// checker enforces; copy out any pointers you need to keep.
+///
+/// # Example
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_entity_ptr::{EntityHandle, HasChildren, PtrArena, WorldExt};
+///
+/// #[derive(Component)]
+/// struct Kids(Vec<EntityHandle>);
+///
+/// impl HasChildren for Kids {
+///     fn children_handles(&self) -> &[EntityHandle] {
+///         &self.0
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// let leaf = world.spawn_empty().id();
+/// let root = world.spawn(Kids(vec![EntityHandle::new(leaf)])).id();
+///
+/// let mut arena = PtrArena::new();
+/// for _frame in 0..3 {
+///     let subtree = arena.descendants::<Kids>(world.entity_ptr(root));
+///     assert_eq!(subtree.len(), 1);
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct PtrArena {
+    buffer: Vec<EntityPtr>,
+}
+
+impl PtrArena {
+    /// Creates an empty arena.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { buffer: Vec::new() }
+    }
+
+    /// Creates an arena that can hold `capacity` pointers before reallocating.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Collects the descendants of `ptr` in depth-first pre-order, reusing storage.
+    ///
+    /// The root is excluded, matching `EntityPtrNavMany::descendants()`.
+    pub fn descendants<T: Component + HasChildren>(&mut self, ptr: EntityPtr) -> &[EntityPtr] {
+        self.buffer.clear();
+        ptr.nav_many().extend_descendants::<T>(&mut self.buffer);
+        &self.buffer
+    }
+
+    /// Collects the immediate children of `ptr`, reusing storage.
+    pub fn children<T: Component + HasChildren>(&mut self, ptr: EntityPtr) -> &[EntityPtr] {
+        self.buffer.clear();
+        self.buffer.extend(ptr.nav_many().children::<T>());
+        &self.buffer
+    }
+
+    /// Empties the arena, keeping its capacity for the next use.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+    }
+
+    /// Returns the number of pointers the arena can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buffer.capacity()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WorldExt;
+    use crate::handle::EntityHandle;
+    use bevy_ecs::world::World;
+
+    #[derive(Component)]
+    struct Kids(Vec<EntityHandle>);
+
+    impl HasChildren for Kids {
+        fn children_handles(&self) -> &[EntityHandle] {
+            &self.0
+        }
+    }
+
+    #[test]
+    fn arena_reuses_storage() {
+        let mut world = World::new();
+        let grandchild = world.spawn_empty().id();
+        let child = world.spawn(Kids(vec![EntityHandle::new(grandchild)])).id();
+        let sibling = world.spawn_empty().id();
+        let root = world
+            .spawn(Kids(vec![
+                EntityHandle::new(child),
+                EntityHandle::new(sibling),
+            ]))
+            .id();
+
+        let mut arena = PtrArena::new();
+        let all: Vec<_> = arena
+            .descendants::<Kids>(world.entity_ptr(root))
+            .iter()
+            .map(|p| p.entity())
+            .collect();
+        assert_eq!(all, vec![child, grandchild, sibling]);
+        let capacity = arena.capacity();
+
+        let kids: Vec<_> = arena
+            .children::<Kids>(world.entity_ptr(root))
+            .iter()
+            .map(|p| p.entity())
+            .collect();
+        assert_eq!(kids, vec![child, sibling]);
+
+        assert_eq!(arena.descendants::<Kids>(world.entity_ptr(child)).len(), 1);
+        assert_eq!(arena.capacity(), capacity);
+
+        arena.reset();
+        assert_eq!(arena.capacity(), capacity);
+        assert!(
+            arena
+                .descendants::<Kids>(world.entity_ptr(sibling))
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,154 @@
+//! Debug views - printing selected component values of an entity.
+//!
+//! The `Debug` impls on `EntityPtr` and `BoundEntity` only show the entity ID.
+//! `DebugEntity` lets callers pick which components to print alongside it.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::world::World;
+
+use crate::ptr::EntityPtr;
+
+/// A set of component types whose values `DebugEntity` prints.
+///
+/// Implemented for tuples of up to four `Component + Debug` types, e.g. `(Name,)` or
+/// `(Name, Health)`.
+pub trait DebugComponents {
+    /// Appends one field per component type to `out`.
+    ///
+    /// Missing components are printed as `None`.
+    fn debug_fields(world: &World, entity: Entity, out: &mut fmt::DebugStruct<'_, '_>);
+}
+
+macro_rules! impl_debug_components {
+    ($($name:ident),+) => {
+        impl<$($name: Component + fmt::Debug),+> DebugComponents for ($($name,)+) {
+            fn debug_fields(world: &World, entity: Entity, out: &mut fmt::DebugStruct<'_, '_>) {
+                $(
+                    out.field(short_type_name::<$name>(), &world.get::<$name>(entity));
+                )+
+            }
+        }
+    };
+}
+
+impl_debug_components!(A);
+impl_debug_components!(A, B);
+impl_debug_components!(A, B, C);
+impl_debug_components!(A, B, C, D);
+
+/// Strips the module path from a type name, keeping generic arguments intact.
+pub(crate) fn short_type_name<T>() -> &'static str {
+    let full = std::any::type_name::<T>();
+    let base = full.split('<').next().unwrap_or(full);
+    let start = base.rfind("::").map_or(0, |i| i + 2);
+    &full[start..]
+}
+
+/// A `Debug` view of an entity that prints the values of the components in `C`.
+///
+/// Created by `EntityPtr::debug_view()`.
+///
+/// # Example
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_entity_ptr::WorldExt;
+///
+/// #[derive(Component, Debug)]
+/// struct Name(&'static str);
+///
+/// #[derive(Component, Debug)]
+/// struct Health(i32);
+///
+/// let mut world = World::new();
+/// let entity = world.spawn(Name("hero")).id();
+///
+/// let view = world.entity_ptr(entity).debug_view::<(Name, Health)>();
+/// let text = format!("{view:?}");
+/// assert!(text.contains(r#"Name: Some(Name("hero"))"#));
+/// assert!(text.contains("Health: None"));
+/// ```
+pub struct DebugEntity<C> {
+    ptr: EntityPtr,
+    _marker: PhantomData<fn() -> C>,
+}
+
+impl<C: DebugComponents> DebugEntity<C> {
+    #[inline]
+    pub(crate) const fn new(ptr: EntityPtr) -> Self {
+        Self {
+            ptr,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C: DebugComponents> fmt::Debug for DebugEntity<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut out = f.debug_struct("DebugEntity");
+        out.field("entity", &self.ptr.entity());
+        C::debug_fields(self.ptr.world(), self.ptr.entity(), &mut out);
+        out.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WorldExt;
+
+    #[derive(Component, Debug)]
+    struct Name(&'static str);
+
+    impl fmt::Display for Name {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "<{}>", self.0)
+        }
+    }
+
+    #[derive(Component, Debug)]
+    struct Health(i32);
+
+    #[derive(Component, Debug)]
+    struct Armor;
+
+    #[test]
+    fn debug_view_prints_selected_components() {
+        let mut world = World::new();
+        let entity = world.spawn((Name("hero"), Health(10))).id();
+
+        let ptr = world.entity_ptr(entity);
+        assert_eq!(ptr.get::<Health>().unwrap().0, 10);
+        let text = format!("{:?}", ptr.debug_view::<(Name, Health, Armor)>());
+
+        assert!(text.starts_with(&format!("DebugEntity {{ entity: {entity:?}")));
+        assert!(text.contains(r#"Name: Some(Name("hero"))"#));
+        assert!(text.contains("Health: Some(Health(10))"));
+        assert!(text.contains("Armor: None"));
+    }
+
+    #[test]
+    fn display_component() {
+        let mut world = World::new();
+        let named = world.spawn(Name("boss")).id();
+        let unnamed = world.spawn(Health(1)).id();
+
+        assert_eq!(
+            world.entity_ptr(named).display_component::<Name>(),
+            Some("<boss>".to_string())
+        );
+        assert_eq!(world.entity_ptr(unnamed).display_component::<Name>(), None);
+    }
+
+    #[test]
+    fn short_type_name_strips_paths() {
+        assert_eq!(short_type_name::<Name>(), "Name");
+        assert_eq!(
+            short_type_name::<Vec<Name>>(),
+            "Vec<bevy_entity_ptr::debug::tests::Name>"
+        );
+    }
+}
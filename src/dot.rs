@@ -0,0 +1,134 @@
+//! Graphviz export of entity hierarchies (requires the `dot` feature).
+
+use std::fmt::Write;
+
+use bevy_ecs::component::Component;
+
+use crate::nav::HasChildren;
+use crate::ptr::{EntityPtr, EntityPtrNavMany};
+
+impl EntityPtrNavMany {
+    /// Renders this entity's subtree as a Graphviz DOT digraph.
+    ///
+    /// Each node is named by its `Entity` and labelled with `label(node)`; one edge is
+    /// emitted per parent-child link. Nodes are listed in depth-first pre-order and
+    /// edges follow `child_edges()`, so the output is deterministic for a given
+    /// world. Render it with e.g. `dot -Tsvg`. The hierarchy formed by `T` must be
+    /// acyclic.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_entity_ptr::{EntityHandle, HasChildren, WorldExt};
+    ///
+    /// #[derive(Component)]
+    /// struct Kids(Vec<EntityHandle>);
+    ///
+    /// impl HasChildren for Kids {
+    ///     fn children_handles(&self) -> &[EntityHandle] {
+    ///         &self.0
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// let leaf = world.spawn_empty().id();
+    /// let root = world.spawn(Kids(vec![EntityHandle::new(leaf)])).id();
+    ///
+    /// let dot = world
+    ///     .entity_ptr(root)
+    ///     .nav_many()
+    ///     .to_dot::<Kids, _>(|p| format!("node {}", p.entity().index()));
+    /// assert!(dot.starts_with("digraph {"));
+    /// assert!(dot.contains(&format!("\"{root}\" -> \"{leaf}\";")));
+    /// ```
+    pub fn to_dot<T, F>(self, mut label: F) -> String
+    where
+        T: Component + HasChildren,
+        F: FnMut(EntityPtr) -> String,
+    {
+        let root = self.inner();
+        let mut out = String::from("digraph {\n");
+        for node in std::iter::once(root).chain(self.descendants::<T>()) {
+            let text = escape_label(&label(node));
+            // Writing to a String cannot fail
+            let _ = writeln!(out, "    \"{}\" [label=\"{text}\"];", node.entity());
+        }
+        for (parent, child) in self.child_edges::<T>() {
+            let _ = writeln!(out, "    \"{parent}\" -> \"{child}\";");
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// Escapes quotes and backslashes so a label stays inside its DOT string.
+fn escape_label(label: &str) -> String {
+    let mut escaped = String::with_capacity(label.len());
+    for c in label.chars() {
+        if matches!(c, '"' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WorldExt;
+    use crate::handle::EntityHandle;
+    use bevy_ecs::world::World;
+
+    #[derive(Component)]
+    struct Name(&'static str);
+
+    #[derive(Component)]
+    struct Kids(Vec<EntityHandle>);
+
+    impl HasChildren for Kids {
+        fn children_handles(&self) -> &[EntityHandle] {
+            &self.0
+        }
+    }
+
+    #[test]
+    fn to_dot_lists_nodes_and_edges() {
+        let mut world = World::new();
+        let c = world.spawn(Name("c")).id();
+        let a = world
+            .spawn((Name("a"), Kids(vec![EntityHandle::new(c)])))
+            .id();
+        let b = world.spawn(Name("say \"b\"")).id();
+        let root = world
+            .spawn((
+                Name("root"),
+                Kids(vec![EntityHandle::new(a), EntityHandle::new(b)]),
+            ))
+            .id();
+
+        let dot = world
+            .entity_ptr(root)
+            .nav_many()
+            .to_dot::<Kids, _>(|p| p.get::<Name>().unwrap().0.to_owned());
+        let expected = format!(
+            "digraph {{\n\
+             \x20   \"{root}\" [label=\"root\"];\n\
+             \x20   \"{a}\" [label=\"a\"];\n\
+             \x20   \"{c}\" [label=\"c\"];\n\
+             \x20   \"{b}\" [label=\"say \\\"b\\\"\"];\n\
+             \x20   \"{root}\" -> \"{a}\";\n\
+             \x20   \"{root}\" -> \"{b}\";\n\
+             \x20   \"{a}\" -> \"{c}\";\n\
+             }}\n"
+        );
+        assert_eq!(dot, expected);
+
+        // Deterministic across calls
+        let again = world
+            .entity_ptr(root)
+            .nav_many()
+            .to_dot::<Kids, _>(|p| p.get::<Name>().unwrap().0.to_owned());
+        assert_eq!(dot, again);
+    }
+}
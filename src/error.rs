@@ -0,0 +1,59 @@
+//! Error types for `Result`-returning entity access.
+
+use bevy_ecs::entity::Entity;
+
+/// Why an entity access or follow failed.
+///
+/// Returned by the `*_res` variants of the follow methods so traversal code can
+/// propagate failures with `?` instead of converting `None` by hand.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EntityAccessError {
+    /// The entity no longer exists.
+    Despawned {
+        /// The entity that was accessed.
+        entity: Entity,
+    },
+    /// The entity exists but does not have the component.
+    ComponentMissing {
+        /// The entity that was accessed.
+        entity: Entity,
+        /// Type name of the missing component.
+        component: &'static str,
+    },
+    /// The component is present but its optional link is `None`.
+    LinkEmpty {
+        /// The entity holding the link.
+        entity: Entity,
+        /// Type name of the component holding the link.
+        component: &'static str,
+    },
+}
+
+impl EntityAccessError {
+    /// Returns the entity the failed access started from.
+    #[inline]
+    pub const fn entity(&self) -> Entity {
+        match *self {
+            Self::Despawned { entity }
+            | Self::ComponentMissing { entity, .. }
+            | Self::LinkEmpty { entity, .. } => entity,
+        }
+    }
+}
+
+impl std::fmt::Display for EntityAccessError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Despawned { entity } => write!(f, "entity {entity} is despawned"),
+            Self::ComponentMissing { entity, component } => {
+                write!(f, "entity {entity} has no `{component}` component")
+            }
+            Self::LinkEmpty { entity, component } => {
+                write!(f, "`{component}` on entity {entity} holds no link")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EntityAccessError {}
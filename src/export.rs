@@ -0,0 +1,145 @@
+//! JSON export of entity hierarchies (requires the `serde` feature).
+
+use bevy_ecs::component::Component;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::nav::HasChildren;
+use crate::ptr::{EntityPtr, EntityPtrNavMany};
+
+impl EntityPtrNavMany {
+    /// Exports this entity's subtree as nested JSON.
+    ///
+    /// Each node becomes `{"data": f(node), "children": [...]}`, with children in
+    /// stored order. Useful for scene debugging dumps and golden-file tests. The
+    /// hierarchy formed by `T` must be acyclic.
+    ///
+    /// # Errors
+    /// Returns the first error from serializing a node's data.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_entity_ptr::{EntityHandle, HasChildren, WorldExt};
+    ///
+    /// #[derive(Component)]
+    /// struct Kids(Vec<EntityHandle>);
+    ///
+    /// impl HasChildren for Kids {
+    ///     fn children_handles(&self) -> &[EntityHandle] {
+    ///         &self.0
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// let leaf = world.spawn_empty().id();
+    /// let root = world.spawn(Kids(vec![EntityHandle::new(leaf)])).id();
+    ///
+    /// let json = world
+    ///     .entity_ptr(root)
+    ///     .nav_many()
+    ///     .export_tree::<Kids, _, _>(|p| p.entity() == root)
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     json,
+    ///     serde_json::json!({"data": true, "children": [{"data": false, "children": []}]})
+    /// );
+    /// ```
+    pub fn export_tree<T, S, F>(self, mut f: F) -> Result<Value, serde_json::Error>
+    where
+        T: Component + HasChildren,
+        S: Serialize,
+        F: FnMut(EntityPtr) -> S,
+    {
+        export_node::<T, S, F>(self.inner(), &mut f)
+    }
+}
+
+fn export_node<T, S, F>(node: EntityPtr, f: &mut F) -> Result<Value, serde_json::Error>
+where
+    T: Component + HasChildren,
+    S: Serialize,
+    F: FnMut(EntityPtr) -> S,
+{
+    let data = serde_json::to_value(f(node))?;
+    let children = node
+        .nav_many()
+        .children::<T>()
+        .map(|child| export_node::<T, S, F>(child, f))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut object = Map::new();
+    object.insert("data".to_owned(), data);
+    object.insert("children".to_owned(), Value::Array(children));
+    Ok(Value::Object(object))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WorldExt;
+    use crate::handle::EntityHandle;
+    use bevy_ecs::world::World;
+    use serde_json::json;
+
+    #[derive(Component)]
+    struct Name(&'static str);
+
+    #[derive(Component)]
+    struct Kids(Vec<EntityHandle>);
+
+    impl HasChildren for Kids {
+        fn children_handles(&self) -> &[EntityHandle] {
+            &self.0
+        }
+    }
+
+    #[test]
+    fn export_tree_round_trip() {
+        let mut world = World::new();
+        let c = world.spawn(Name("c")).id();
+        let a = world
+            .spawn((Name("a"), Kids(vec![EntityHandle::new(c)])))
+            .id();
+        let b = world.spawn(Name("b")).id();
+        let root = world
+            .spawn((
+                Name("root"),
+                Kids(vec![EntityHandle::new(a), EntityHandle::new(b)]),
+            ))
+            .id();
+
+        let exported = world
+            .entity_ptr(root)
+            .nav_many()
+            .export_tree::<Kids, _, _>(|p| p.get::<Name>().map(|n| n.0))
+            .unwrap();
+        let expected = json!({
+            "data": "root",
+            "children": [
+                {"data": "a", "children": [{"data": "c", "children": []}]},
+                {"data": "b", "children": []},
+            ],
+        });
+        assert_eq!(exported, expected);
+
+        let text = serde_json::to_string(&exported).unwrap();
+        let parsed: Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(parsed, exported);
+    }
+
+    #[test]
+    fn export_tree_serialization_error() {
+        use std::collections::HashMap;
+
+        let mut world = World::new();
+        let root = world.spawn(Name("root")).id();
+
+        // Non-string map keys cannot become JSON object keys
+        let result = world
+            .entity_ptr(root)
+            .nav_many()
+            .export_tree::<Kids, _, _>(|_| HashMap::from([((1, 2), 3)]));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,246 @@
+//! Depth-limited pointers - a safety rail for hand-written recursive traversals.
+//!
+//! Recursive helpers over `EntityPtr` overflow the stack on cyclic or very deep
+//! data. `GuardedPtr` carries a hop budget that every navigation step spends.
+
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+
+use crate::handle::EntityHandle;
+use crate::ptr::EntityPtr;
+
+/// An `EntityPtr` with a remaining-depth budget.
+///
+/// Created by `EntityPtr::guarded()`. Each `follow*` or `children` step yields
+/// pointers with one less hop remaining, and yields nothing once the budget is
+/// spent, so recursion written against `GuardedPtr` always terminates.
+///
+/// # Example
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_entity_ptr::{EntityHandle, GuardedPtr, WorldExt};
+///
+/// #[derive(Component)]
+/// struct Next(EntityHandle);
+///
+/// fn chain_len(ptr: GuardedPtr) -> usize {
+///     1 + ptr.follow::<Next, _>(|n| n.0).map_or(0, chain_len)
+/// }
+///
+/// let mut world = World::new();
+/// let a = world.spawn_empty().id();
+/// // A self-loop would recurse forever with a plain `EntityPtr`
+/// world.entity_mut(a).insert(Next(EntityHandle::new(a)));
+///
+/// assert_eq!(chain_len(world.entity_ptr(a).guarded(5)), 6);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct GuardedPtr {
+    ptr: EntityPtr,
+    remaining: usize,
+}
+
+impl GuardedPtr {
+    #[inline]
+    pub(crate) const fn new(ptr: EntityPtr, remaining: usize) -> Self {
+        Self { ptr, remaining }
+    }
+
+    /// Returns the underlying `EntityPtr`.
+    #[inline]
+    pub const fn ptr(self) -> EntityPtr {
+        self.ptr
+    }
+
+    /// Returns the underlying `Entity`.
+    #[inline]
+    pub const fn entity(self) -> Entity {
+        self.ptr.entity()
+    }
+
+    /// Returns how many more hops may be taken from this pointer.
+    #[inline]
+    pub const fn remaining(self) -> usize {
+        self.remaining
+    }
+
+    /// Gets a component from this entity. See [`EntityPtr::get`].
+    #[inline]
+    #[must_use]
+    pub fn get<T: Component>(self) -> Option<&'static T> {
+        self.ptr.get::<T>()
+    }
+
+    /// Checks if this entity has a component of type `T`.
+    #[inline]
+    pub fn has<T: Component>(self) -> bool {
+        self.ptr.has::<T>()
+    }
+
+    /// Wraps `next` with one hop less, or returns `None` if the budget is spent.
+    #[inline]
+    fn step(self, next: EntityPtr) -> Option<GuardedPtr> {
+        self.remaining
+            .checked_sub(1)
+            .map(|remaining| GuardedPtr::new(next, remaining))
+    }
+
+    /// Follows a reference component, spending one hop.
+    ///
+    /// Returns `None` if the budget is spent or `EntityPtr::follow` would.
+    #[inline]
+    #[must_use]
+    pub fn follow<T, F>(self, f: F) -> Option<GuardedPtr>
+    where
+        T: Component,
+        F: FnOnce(&T) -> EntityHandle,
+    {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.step(self.ptr.follow::<T, F>(f)?)
+    }
+
+    /// Follows an optional reference component, spending one hop.
+    ///
+    /// Returns `None` if the budget is spent or `EntityPtr::follow_opt` would.
+    #[inline]
+    #[must_use]
+    pub fn follow_opt<T, F>(self, f: F) -> Option<GuardedPtr>
+    where
+        T: Component,
+        F: FnOnce(&T) -> Option<EntityHandle>,
+    {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.step(self.ptr.follow_opt::<T, F>(f)?)
+    }
+
+    /// Follows a handle held by this entity, spending one hop.
+    ///
+    /// Returns `None` if the budget is spent.
+    #[inline]
+    #[must_use]
+    pub fn follow_handle(self, handle: EntityHandle) -> Option<GuardedPtr> {
+        self.step(self.ptr.follow_handle(handle))
+    }
+
+    /// Follows a component holding multiple references, spending one hop for each.
+    ///
+    /// Yields nothing if the budget is spent.
+    pub fn follow_many<T, F>(self, f: F) -> impl Iterator<Item = GuardedPtr>
+    where
+        T: Component,
+        F: FnOnce(&T) -> &[EntityHandle],
+    {
+        let targets = if self.remaining == 0 {
+            &[]
+        } else {
+            self.ptr.get::<T>().map(f).unwrap_or(&[])
+        };
+        targets.iter().filter_map(move |&h| self.follow_handle(h))
+    }
+
+    /// Iterates the immediate children via `T`, spending one hop for each.
+    ///
+    /// Yields nothing if the budget is spent.
+    #[cfg(feature = "nav-traits")]
+    pub fn children<T: Component + crate::nav::HasChildren>(
+        self,
+    ) -> impl Iterator<Item = GuardedPtr> {
+        self.follow_many::<T, _>(crate::nav::HasChildren::children_handles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WorldExt;
+    use bevy_ecs::world::World;
+
+    #[derive(Component)]
+    struct Next(EntityHandle);
+
+    #[derive(Component)]
+    struct Links(Vec<EntityHandle>);
+
+    #[cfg(feature = "nav-traits")]
+    impl crate::nav::HasChildren for Links {
+        fn children_handles(&self) -> &[EntityHandle] {
+            &self.0
+        }
+    }
+
+    fn walk(ptr: GuardedPtr, visited: &mut Vec<Entity>) {
+        visited.push(ptr.entity());
+        if let Some(next) = ptr.follow::<Next, _>(|n| n.0) {
+            walk(next, visited);
+        }
+    }
+
+    #[test]
+    fn guarded_follow_stops_on_cycle() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn(Next(EntityHandle::new(a))).id();
+        world.entity_mut(a).insert(Next(EntityHandle::new(b)));
+
+        let mut visited = Vec::new();
+        walk(world.entity_ptr(a).guarded(3), &mut visited);
+        assert_eq!(visited, vec![a, b, a, b]);
+
+        let exhausted = world.entity_ptr(a).guarded(0);
+        assert!(exhausted.follow::<Next, _>(|n| n.0).is_none());
+        assert!(exhausted.follow_handle(EntityHandle::new(b)).is_none());
+    }
+
+    #[test]
+    fn guarded_follow_many_spends_budget() {
+        let mut world = World::new();
+        let hub = world.spawn_empty().id();
+        let spoke = world.spawn(Links(vec![EntityHandle::new(hub)])).id();
+        world.entity_mut(hub).insert(Links(vec![
+            EntityHandle::new(spoke),
+            EntityHandle::new(hub),
+        ]));
+
+        fn count(ptr: GuardedPtr) -> usize {
+            1 + ptr
+                .follow_many::<Links, _>(|l| &l.0)
+                .map(count)
+                .sum::<usize>()
+        }
+
+        // Each level fans out, but depth 2 bounds the recursion
+        let guarded = world.entity_ptr(hub).guarded(2);
+        assert_eq!(guarded.follow_many::<Links, _>(|l| &l.0).count(), 2);
+        assert!(
+            guarded
+                .follow_many::<Links, _>(|l| &l.0)
+                .all(|p| p.remaining() == 1)
+        );
+        // hub -> {spoke -> hub, hub -> {spoke, hub}}
+        assert_eq!(count(guarded), 1 + (1 + 1) + (1 + 2));
+        assert_eq!(count(world.entity_ptr(hub).guarded(0)), 1);
+    }
+
+    #[cfg(feature = "nav-traits")]
+    #[test]
+    fn guarded_children_stop_at_depth() {
+        let mut world = World::new();
+        let node = world.spawn_empty().id();
+        world
+            .entity_mut(node)
+            .insert(Links(vec![EntityHandle::new(node)]));
+
+        let mut depth = 0;
+        let mut current = world.entity_ptr(node).guarded(4);
+        while let Some(child) = current.children::<Links>().next() {
+            depth += 1;
+            current = child;
+        }
+        assert_eq!(depth, 4);
+        assert_eq!(current.remaining(), 0);
+    }
+}
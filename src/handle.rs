@@ -2,10 +2,20 @@
 //!
 //! This module provides safe, explicit entity access requiring a `&World` parameter.
 
-use bevy_ecs::component::Component;
-use bevy_ecs::entity::Entity;
+use std::collections::HashSet;
+use std::marker::PhantomData;
+
+use bevy_ecs::change_detection::Ref;
+use bevy_ecs::component::{Component, ComponentId};
+use bevy_ecs::entity::{Entity, EntityLocation};
 use bevy_ecs::world::World;
 
+use crate::debug::short_type_name;
+#[cfg(feature = "nav-traits")]
+use crate::nav::HasChildren;
+use crate::ptr::{EntityPtr, WorldRef};
+use crate::snapshot::CloneComponents;
+
 /// A lightweight handle to an entity that can be stored in components.
 ///
 /// This is a newtype over Bevy's `Entity` that provides ergonomic access methods
@@ -27,12 +37,30 @@ impl EntityHandle {
         Self(entity)
     }
 
+    /// Creates a new handle, rejecting `Entity::PLACEHOLDER`.
+    ///
+    /// Catches placeholder entities before they end up stored in a relationship
+    /// component. Use `new` where a placeholder is an intentional sentinel.
+    #[inline]
+    #[must_use]
+    pub fn checked_new(entity: Entity) -> Option<Self> {
+        (entity != Entity::PLACEHOLDER).then_some(Self(entity))
+    }
+
     /// Returns the underlying `Entity`.
     #[inline]
     pub const fn entity(self) -> Entity {
         self.0
     }
 
+    /// Checks whether this handle refers to `entity`.
+    ///
+    /// Equivalent to `handle == entity`, for use where a method reads better.
+    #[inline]
+    pub fn equals_entity(self, entity: Entity) -> bool {
+        self.0 == entity
+    }
+
     /// Gets a component from the referenced entity.
     ///
     /// Returns `None` if the entity doesn't exist or doesn't have the component.
@@ -42,6 +70,60 @@ impl EntityHandle {
         world.get::<T>(self.0)
     }
 
+    /// Gets two components from the referenced entity.
+    ///
+    /// Returns `None` unless the entity exists and has both components.
+    #[inline]
+    #[must_use]
+    pub fn get2<A: Component, B: Component>(self, world: &World) -> Option<(&A, &B)> {
+        Some((world.get::<A>(self.0)?, world.get::<B>(self.0)?))
+    }
+
+    /// Gets three components from the referenced entity.
+    ///
+    /// Returns `None` unless the entity exists and has all three components.
+    #[inline]
+    #[must_use]
+    pub fn get3<A: Component, B: Component, C: Component>(
+        self,
+        world: &World,
+    ) -> Option<(&A, &B, &C)> {
+        Some((
+            world.get::<A>(self.0)?,
+            world.get::<B>(self.0)?,
+            world.get::<C>(self.0)?,
+        ))
+    }
+
+    /// Reads a reference component and returns the handle it points to.
+    ///
+    /// Stays on the pure-handle path: no binding, just the next ID, e.g. when
+    /// walking links in save-data processing. Returns `None` if the entity doesn't
+    /// exist or doesn't have the component.
+    #[inline]
+    #[must_use]
+    pub fn follow<T, F>(self, world: &World, f: F) -> Option<EntityHandle>
+    where
+        T: Component,
+        F: FnOnce(&T) -> EntityHandle,
+    {
+        self.get::<T>(world).map(f)
+    }
+
+    /// Reads an optional reference component and returns the handle it points to.
+    ///
+    /// Returns `None` if the entity doesn't exist, doesn't have the component, or
+    /// the reference is `None`.
+    #[inline]
+    #[must_use]
+    pub fn follow_opt<T, F>(self, world: &World, f: F) -> Option<EntityHandle>
+    where
+        T: Component,
+        F: FnOnce(&T) -> Option<EntityHandle>,
+    {
+        self.get::<T>(world).and_then(f)
+    }
+
     /// Checks if the entity has a component of type `T`.
     ///
     /// Returns `false` if the entity doesn't exist.
@@ -56,11 +138,36 @@ impl EntityHandle {
         world.get_entity(self.0).is_ok()
     }
 
+    /// Describes the handle and its status in `world`, for log lines.
+    ///
+    /// Produces `EntityHandle(3v1) [Live: Name="hero"]` when the entity is alive and
+    /// has the `Named` component `N`, `EntityHandle(3v1) [Live]` when it lacks `N`,
+    /// and `EntityHandle(3v1) [Despawned]` otherwise.
+    #[must_use]
+    pub fn debug_resolve<N: Component + Named>(self, world: &World) -> String {
+        if !self.is_alive(world) {
+            return format!("{self} [Despawned]");
+        }
+        match self.get::<N>(world) {
+            Some(n) => format!("{self} [Live: {}={:?}]", short_type_name::<N>(), n.name()),
+            None => format!("{self} [Live]"),
+        }
+    }
+
     /// Binds this handle to a world, creating a `BoundEntity` for fluent access.
     #[inline]
     pub fn bind(self, world: &World) -> BoundEntity<'_> {
         BoundEntity::new(self.0, world)
     }
+
+    /// Converts this handle into an `EntityPtr` for the world behind `world_ref`.
+    ///
+    /// Method-call form of `WorldRef::from_handle`. Safe, since `WorldRef::new`
+    /// already carries the lifetime contract.
+    #[inline]
+    pub fn as_ptr(self, world_ref: WorldRef) -> EntityPtr {
+        world_ref.from_handle(self)
+    }
 }
 
 impl std::fmt::Display for EntityHandle {
@@ -83,9 +190,295 @@ impl From<EntityHandle> for Entity {
     }
 }
 
+impl PartialEq<Entity> for EntityHandle {
+    #[inline]
+    fn eq(&self, other: &Entity) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<EntityHandle> for Entity {
+    #[inline]
+    fn eq(&self, other: &EntityHandle) -> bool {
+        *self == other.0
+    }
+}
+
 // Send + Sync auto-derived: EntityHandle is #[repr(transparent)] over Entity,
 // which is Send + Sync.
 
+/// An `EntityHandle` tagged with a component the target entity is expected to have.
+///
+/// Store `TypedEntityHandle<Weapon>` instead of a bare `EntityHandle` to document (and
+/// check at follow time, see `EntityPtr::follow_typed()`) that the target has `Weapon`.
+/// The tag is not enforced at construction: the target may lose or never have `M`.
+///
+/// # Size
+/// 8 bytes (same as `Entity`)
+///
+/// # Thread Safety
+/// `Send + Sync` regardless of `M`.
+#[repr(transparent)]
+pub struct TypedEntityHandle<M> {
+    handle: EntityHandle,
+    _marker: PhantomData<fn() -> M>,
+}
+
+impl<M> TypedEntityHandle<M> {
+    /// Creates a new typed handle from an entity.
+    #[inline]
+    pub const fn new(entity: Entity) -> Self {
+        Self {
+            handle: EntityHandle::new(entity),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the untyped `EntityHandle`.
+    #[inline]
+    pub const fn handle(self) -> EntityHandle {
+        self.handle
+    }
+
+    /// Returns the underlying `Entity`.
+    #[inline]
+    pub const fn entity(self) -> Entity {
+        self.handle.entity()
+    }
+}
+
+impl<M: Component> TypedEntityHandle<M> {
+    /// Gets the tagged component `M` from the referenced entity.
+    ///
+    /// Returns `None` if the entity doesn't exist or doesn't have `M`.
+    #[inline]
+    #[must_use]
+    pub fn get(self, world: &World) -> Option<&M> {
+        self.handle.get::<M>(world)
+    }
+}
+
+impl<M> Clone for TypedEntityHandle<M> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M> Copy for TypedEntityHandle<M> {}
+
+impl<M> PartialEq for TypedEntityHandle<M> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.handle == other.handle
+    }
+}
+
+impl<M> Eq for TypedEntityHandle<M> {}
+
+impl<M> std::hash::Hash for TypedEntityHandle<M> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.handle.hash(state);
+    }
+}
+
+impl<M> std::fmt::Debug for TypedEntityHandle<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("TypedEntityHandle")
+            .field(&self.handle.entity())
+            .finish()
+    }
+}
+
+impl<M> From<TypedEntityHandle<M>> for EntityHandle {
+    #[inline]
+    fn from(typed: TypedEntityHandle<M>) -> Self {
+        typed.handle
+    }
+}
+
+/// Proof that an entity was alive when it was captured.
+///
+/// Created by `EntityPtr::snapshot()` / `BoundEntity::snapshot()`, which return
+/// `None` for despawned entities. Code that checks liveness once and then reuses
+/// the answer can carry the snapshot instead of re-querying the world. Call
+/// `still_valid()` to re-check later; the entity's generation makes a recycled
+/// index read as invalid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EntitySnapshot {
+    entity: Entity,
+}
+
+impl EntitySnapshot {
+    /// Creates a snapshot; callers must have just checked that `entity` is alive.
+    #[inline]
+    pub(crate) const fn new(entity: Entity) -> Self {
+        Self { entity }
+    }
+
+    /// Returns the captured entity.
+    #[inline]
+    pub const fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// Returns a handle to the captured entity.
+    #[inline]
+    pub const fn handle(&self) -> EntityHandle {
+        EntityHandle::new(self.entity)
+    }
+
+    /// Checks whether the captured entity is still alive in `world`.
+    #[inline]
+    #[must_use]
+    pub fn still_valid(&self, world: &World) -> bool {
+        world.get_entity(self.entity).is_ok()
+    }
+}
+
+/// A value that stores zero or more `EntityHandle`s.
+///
+/// Lets generic code discover the outgoing edges of a component without a bespoke
+/// extractor per component. Implemented for `EntityHandle`, `Option<EntityHandle>`,
+/// `Vec<EntityHandle>` and `[EntityHandle]`; components implement it by delegating
+/// to their handle fields.
+///
+/// # Example
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_entity_ptr::{EntityHandle, HandleContainer};
+///
+/// #[derive(Component)]
+/// struct Squad {
+///     leader: EntityHandle,
+///     members: Vec<EntityHandle>,
+/// }
+///
+/// impl HandleContainer for Squad {
+///     fn for_each_handle(&self, mut f: impl FnMut(EntityHandle)) {
+///         self.leader.for_each_handle(&mut f);
+///         self.members.for_each_handle(f);
+///     }
+/// }
+/// ```
+pub trait HandleContainer {
+    /// Calls `f` with every stored handle, in storage order.
+    fn for_each_handle(&self, f: impl FnMut(EntityHandle));
+}
+
+impl HandleContainer for EntityHandle {
+    #[inline]
+    fn for_each_handle(&self, mut f: impl FnMut(EntityHandle)) {
+        f(*self);
+    }
+}
+
+impl HandleContainer for Option<EntityHandle> {
+    #[inline]
+    fn for_each_handle(&self, f: impl FnMut(EntityHandle)) {
+        self.iter().copied().for_each(f);
+    }
+}
+
+impl HandleContainer for [EntityHandle] {
+    #[inline]
+    fn for_each_handle(&self, f: impl FnMut(EntityHandle)) {
+        self.iter().copied().for_each(f);
+    }
+}
+
+impl HandleContainer for Vec<EntityHandle> {
+    #[inline]
+    fn for_each_handle(&self, f: impl FnMut(EntityHandle)) {
+        self.as_slice().for_each_handle(f);
+    }
+}
+
+/// A component that carries a display name.
+///
+/// Implement this for your own name component to use `EntityPtr::name_via()` and
+/// `BoundEntity::name_via()` without the crate depending on a specific `Name` type.
+///
+/// # Example
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_entity_ptr::{Named, WorldExt};
+///
+/// #[derive(Component)]
+/// struct Label(String);
+///
+/// impl Named for Label {
+///     fn name(&self) -> &str {
+///         &self.0
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// let e = world.spawn(Label("door".into())).id();
+/// assert_eq!(world.bind_entity(e).name_via::<Label>(), Some("door"));
+/// ```
+pub trait Named {
+    /// Returns the display name.
+    fn name(&self) -> &str;
+}
+
+/// Extension methods for pruning collections of `EntityHandle`s against a world.
+///
+/// Typically used as a cleanup pass on relationship components after a batch of
+/// despawns.
+///
+/// # Example
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_entity_ptr::{EntityHandle, HandleSliceExt};
+///
+/// let mut world = World::new();
+/// let kept = world.spawn_empty().id();
+/// let gone = world.spawn_empty().id();
+/// let mut members = vec![EntityHandle::new(kept), EntityHandle::new(gone)];
+///
+/// world.despawn(gone);
+/// members.retain_alive(&world);
+/// assert_eq!(members, vec![EntityHandle::new(kept)]);
+/// ```
+pub trait HandleSliceExt {
+    /// Removes every handle whose entity is no longer alive, preserving order.
+    fn retain_alive(&mut self, world: &World);
+
+    /// Returns the handles whose entities are still alive, preserving order.
+    #[must_use]
+    fn alive_handles(&self, world: &World) -> Vec<EntityHandle>;
+}
+
+impl HandleSliceExt for Vec<EntityHandle> {
+    #[inline]
+    fn retain_alive(&mut self, world: &World) {
+        self.retain(|h| h.is_alive(world));
+    }
+
+    #[inline]
+    fn alive_handles(&self, world: &World) -> Vec<EntityHandle> {
+        self.iter().copied().filter(|h| h.is_alive(world)).collect()
+    }
+}
+
+/// Filtering adapters for iterators over `BoundEntity`.
+///
+/// The `BoundEntity` counterpart of `EntityPtrIterExt`.
+pub trait BoundEntityIterExt<'w>: Iterator<Item = BoundEntity<'w>> + Sized {
+    /// Skips entities that have been despawned.
+    fn alive(self) -> impl Iterator<Item = BoundEntity<'w>> {
+        self.filter(|b| b.is_alive())
+    }
+
+    /// Keeps only entities that have component `C`.
+    fn with_component<C: Component>(self) -> impl Iterator<Item = BoundEntity<'w>> {
+        self.filter(|b| b.has::<C>())
+    }
+}
+
+impl<'w, I: Iterator<Item = BoundEntity<'w>>> BoundEntityIterExt<'w> for I {}
+
 /// An entity bound to a world reference for fluent, scoped access.
 ///
 /// Created by calling `EntityHandle::bind()` or directly. Provides method chaining
@@ -132,10 +525,185 @@ impl<'w> BoundEntity<'w> {
 
     /// Checks if this entity has a component of type `T`.
     ///
-    /// Returns `false` if the entity doesn't exist.
+    /// Returns `false` if the entity doesn't exist.
+    #[inline]
+    pub fn has<T: Component>(self) -> bool {
+        self.world.get::<T>(self.entity).is_some()
+    }
+
+    /// Gets component `T` from both this entity and `other`.
+    ///
+    /// Returns `None` unless both entities have the component.
+    #[inline]
+    #[must_use]
+    pub fn zip_get<T: Component>(self, other: BoundEntity<'w>) -> Option<(&'w T, &'w T)> {
+        self.get::<T>().zip(other.get::<T>())
+    }
+
+    /// Borrows a slice stored inside component `T`.
+    ///
+    /// See [`EntityPtr::get_slice`].
+    #[inline]
+    #[must_use]
+    pub fn get_slice<T, E, F>(self, f: F) -> Option<&'w [E]>
+    where
+        T: Component,
+        F: FnOnce(&T) -> &[E],
+    {
+        self.get::<T>().map(f)
+    }
+
+    /// Gets two components from this entity.
+    ///
+    /// Returns `None` unless the entity exists and has both components.
+    #[inline]
+    #[must_use]
+    pub fn get2<A: Component, B: Component>(self) -> Option<(&'w A, &'w B)> {
+        Some((self.get::<A>()?, self.get::<B>()?))
+    }
+
+    /// Gets a component and maps it through `f`.
+    ///
+    /// `f` receives a `&'w T`, so it may return borrows into the component.
+    /// Returns `None` if the entity doesn't exist or doesn't have the component.
+    #[inline]
+    #[must_use]
+    pub fn get_map<T, R, F>(self, f: F) -> Option<R>
+    where
+        T: Component,
+        F: FnOnce(&'w T) -> R,
+    {
+        self.get::<T>().map(f)
+    }
+
+    /// Gets a clone of a component, detached from the world borrow.
+    ///
+    /// Returns `None` if the entity doesn't exist or doesn't have the component.
+    #[inline]
+    #[must_use]
+    pub fn get_cloned<T: Component + Clone>(self) -> Option<T> {
+        self.get::<T>().cloned()
+    }
+
+    /// Returns `Some(self)` if `pred` holds for this entity, else `None`.
+    ///
+    /// Mirrors `Option::filter`, so it chains after `follow` and friends.
+    #[inline]
+    #[must_use]
+    pub fn filter<F: FnOnce(&BoundEntity<'w>) -> bool>(self, pred: F) -> Option<BoundEntity<'w>> {
+        pred(&self).then_some(self)
+    }
+
+    /// Gets change-detection `Ref`s to two components at once.
+    ///
+    /// Returns `None` unless the entity exists and has both components.
+    #[inline]
+    #[must_use]
+    pub fn get_ref2<A: Component, B: Component>(self) -> Option<(Ref<'w, A>, Ref<'w, B>)> {
+        let entity = self.world.get_entity(self.entity).ok()?;
+        Some((entity.get_ref::<A>()?, entity.get_ref::<B>()?))
+    }
+
+    /// Clones the components in `C` into an owned tuple.
+    ///
+    /// Returns `None` unless the entity exists and has all of them. See
+    /// [`EntityPtr::snapshot_components`].
+    #[inline]
+    #[must_use]
+    pub fn snapshot_components<C: CloneComponents>(self) -> Option<C> {
+        C::clone_components(self.world, self.entity)
+    }
+
+    /// Gets the name stored in the `Named` component `N`.
+    ///
+    /// Returns `None` if the entity doesn't exist or doesn't have `N`.
+    #[inline]
+    #[must_use]
+    pub fn name_via<N: Component + Named>(self) -> Option<&'w str> {
+        self.get::<N>().map(Named::name)
+    }
+
+    /// Reads a persistent identity from the user-provided stable-id component `S`.
+    ///
+    /// See [`EntityPtr::stable_id`].
+    #[inline]
+    #[must_use]
+    pub fn stable_id<S: Component + Into<u64> + Copy>(self) -> Option<u64> {
+        self.get::<S>().map(|&id| id.into())
+    }
+
+    /// Returns the entity's storage location (archetype, table and rows).
+    ///
+    /// Returns `None` if the entity is despawned.
+    #[inline]
+    #[must_use]
+    pub fn location(self) -> Option<EntityLocation> {
+        self.world.entities().get_spawned(self.entity).ok()
+    }
+
+    /// Checks whether this entity and `other` share an archetype.
+    ///
+    /// Returns `false` if either entity is despawned or `other` is bound to a
+    /// different world. See [`EntityPtr::same_archetype`].
+    #[inline]
+    pub fn same_archetype(self, other: BoundEntity<'_>) -> bool {
+        if !std::ptr::eq(self.world, other.world) {
+            return false;
+        }
+        match (self.location(), other.location()) {
+            (Some(a), Some(b)) => a.archetype_id == b.archetype_id,
+            _ => false,
+        }
+    }
+
+    /// Returns how many components this entity has, or 0 if it is despawned.
+    ///
+    /// See [`EntityPtr::component_count`].
+    #[inline]
+    pub fn component_count(self) -> usize {
+        self.world
+            .get_entity(self.entity)
+            .map_or(0, |entity| entity.archetype().component_count())
+    }
+
+    /// Returns the IDs of this entity's components that pass `predicate`.
+    ///
+    /// Returns an empty `Vec` if the entity is despawned. See
+    /// [`EntityPtr::component_ids_filtered`].
+    pub fn component_ids_filtered(
+        self,
+        mut predicate: impl FnMut(ComponentId) -> bool,
+    ) -> Vec<ComponentId> {
+        self.world
+            .get_entity(self.entity)
+            .map(|entity| {
+                entity
+                    .archetype()
+                    .components()
+                    .iter()
+                    .copied()
+                    .filter(|&id| predicate(id))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Captures an `EntitySnapshot` if this entity is alive.
+    #[inline]
+    #[must_use]
+    pub fn snapshot(self) -> Option<EntitySnapshot> {
+        self.is_alive().then_some(EntitySnapshot::new(self.entity))
+    }
+
+    /// Runs `f` on this entity only if it is still alive.
+    ///
+    /// See [`EntityPtr::and_then_if_alive`].
     #[inline]
-    pub fn has<T: Component>(self) -> bool {
-        self.world.get::<T>(self.entity).is_some()
+    pub fn and_then_if_alive<R, F>(self, f: F) -> Option<R>
+    where
+        F: FnOnce(BoundEntity<'w>) -> Option<R>,
+    {
+        if self.is_alive() { f(self) } else { None }
     }
 
     /// Checks if this entity is still alive.
@@ -144,6 +712,23 @@ impl<'w> BoundEntity<'w> {
         self.world.get_entity(self.entity).is_ok()
     }
 
+    /// Returns `self` if this entity is alive, panicking otherwise.
+    ///
+    /// See [`EntityPtr::expect_alive`].
+    ///
+    /// # Panics
+    /// Panics if the entity has been despawned.
+    #[inline]
+    #[track_caller]
+    pub fn expect_alive(self, msg: &str) -> Self {
+        assert!(
+            self.is_alive(),
+            "{msg}: entity {} is not alive",
+            self.entity
+        );
+        self
+    }
+
     /// Follows a reference component to another entity.
     ///
     /// The component must contain an `EntityHandle`. Use `follow_opt` for optional references.
@@ -159,6 +744,22 @@ impl<'w> BoundEntity<'w> {
         self.get::<T>().map(|c| f(c).bind(self.world))
     }
 
+    /// Follows the handle stored in `L` and fetches `T` on the target, returning both.
+    ///
+    /// Returns `None` if this entity lacks `L` or the target lacks `T`.
+    #[inline]
+    #[must_use]
+    pub fn follow_get<L, T, F>(self, f: F) -> Option<(&'w L, &'w T)>
+    where
+        L: Component,
+        T: Component,
+        F: FnOnce(&L) -> EntityHandle,
+    {
+        let link = self.get::<L>()?;
+        let target = f(link).get::<T>(self.world)?;
+        Some((link, target))
+    }
+
     /// Follows an optional reference component to another entity.
     ///
     /// The extractor function returns `Option<EntityHandle>`.
@@ -175,6 +776,71 @@ impl<'w> BoundEntity<'w> {
             .and_then(|c| f(c).map(|h| h.bind(self.world)))
     }
 
+    /// Follows an optional reference, falling back to `default` when it is unset.
+    ///
+    /// See [`EntityPtr::follow_opt_or`].
+    #[inline]
+    pub fn follow_opt_or<T, F>(self, f: F, default: Entity) -> BoundEntity<'w>
+    where
+        T: Component,
+        F: FnOnce(&T) -> Option<EntityHandle>,
+    {
+        self.follow_opt::<T, F>(f)
+            .unwrap_or(BoundEntity::new(default, self.world))
+    }
+
+    /// Follows a component holding multiple entity references.
+    ///
+    /// The extractor function returns a slice of `EntityHandle`s borrowed from the component.
+    ///
+    /// Returns an empty iterator if this entity doesn't have the component.
+    #[inline]
+    pub fn follow_many<T, F>(self, f: F) -> impl Iterator<Item = BoundEntity<'w>>
+    where
+        T: Component,
+        F: FnOnce(&T) -> &[EntityHandle],
+    {
+        let world = self.world;
+        self.get::<T>()
+            .map(f)
+            .unwrap_or(&[])
+            .iter()
+            .map(move |h| h.bind(world))
+    }
+
+    /// Like `follow_many`, but yields each distinct target once.
+    ///
+    /// See [`EntityPtr::follow_many_unique`].
+    #[inline]
+    pub fn follow_many_unique<T, F>(self, f: F) -> impl Iterator<Item = BoundEntity<'w>>
+    where
+        T: Component,
+        F: FnOnce(&T) -> &[EntityHandle],
+    {
+        let mut seen = HashSet::new();
+        self.follow_many::<T, F>(f)
+            .filter(move |b| seen.insert(b.entity))
+    }
+
+    /// Binds a handle read from this entity to the same world.
+    ///
+    /// The `BoundEntity` counterpart of [`EntityPtr::follow_handle`], for recursive
+    /// code that iterates handles itself. Does not check that the target exists.
+    #[inline]
+    pub const fn follow_handle(self, handle: EntityHandle) -> BoundEntity<'w> {
+        BoundEntity::new(handle.entity(), self.world)
+    }
+
+    /// Borrows the child handle slice stored in `T`.
+    ///
+    /// See [`EntityPtr::children_slice`].
+    #[cfg(feature = "nav-traits")]
+    #[inline]
+    #[must_use]
+    pub fn children_slice<T: Component + HasChildren>(self) -> Option<&'w [EntityHandle]> {
+        self.get::<T>().map(HasChildren::children_handles)
+    }
+
     /// Returns a navigator for this entity, enabling `HasParent`/`HasChildren` navigation.
     ///
     /// This method is always available but navigation methods require the `nav-traits` feature.
@@ -242,13 +908,126 @@ impl std::fmt::Debug for BoundEntityNav<'_> {
     }
 }
 
+/// A world reference with its real lifetime, mirroring the `WorldRef` API without `unsafe`.
+///
+/// Created by `WorldRef::new_scoped()`, `WorldExt::world_guard()` or `WorldScope::new()`. Where `WorldRef` erases the
+/// lifetime to `'static`, `WorldScope<'w>` keeps it, so every entity it yields is a
+/// `BoundEntity<'w>` and the borrow checker prevents the World from being mutated or
+/// dropped while any of them are in use. Use it outside Bevy systems, where the
+/// `WorldRef` safety contract cannot be guaranteed structurally.
+///
+/// # Example
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_entity_ptr::{EntityHandle, WorldRef};
+///
+/// #[derive(Component)]
+/// struct Target(EntityHandle);
+///
+/// let mut world = World::new();
+/// let target = world.spawn_empty().id();
+/// let source = world.spawn(Target(EntityHandle::new(target))).id();
+///
+/// // No unsafe: the scope borrows `world` for as long as it is used.
+/// let scope = WorldRef::new_scoped(&world);
+/// let followed = scope.entity(source).follow::<Target, _>(|t| t.0).unwrap();
+/// assert_eq!(followed.entity(), target);
+/// ```
+///
+/// Mutating the World while the scope is still in use is rejected at compile time:
+/// ```compile_fail
+/// use bevy_ecs::prelude::*;
+/// use bevy_entity_ptr::WorldRef;
+///
+/// let mut world = World::new();
+/// let entity = world.spawn_empty().id();
+///
+/// let scope = WorldRef::new_scoped(&world);
+/// let bound = scope.entity(entity);
+/// world.despawn(entity); // error: `world` is borrowed by `scope`
+/// assert!(bound.is_alive());
+/// ```
+///
+/// # Size
+/// 8 bytes (`&World`)
+///
+/// # Thread Safety
+/// NOT `Send` - borrows `&World` so must stay on the creating thread.
+#[derive(Clone, Copy)]
+pub struct WorldScope<'w> {
+    world: &'w World,
+}
+
+impl<'w> WorldScope<'w> {
+    /// Creates a new scope borrowing the given world.
+    #[inline]
+    pub const fn new(world: &'w World) -> Self {
+        Self { world }
+    }
+
+    /// Gets a `BoundEntity` for the given entity.
+    ///
+    /// Returns a `BoundEntity` regardless of whether the entity exists.
+    /// Use `BoundEntity::is_alive()` to check validity.
+    #[inline]
+    pub const fn entity(&self, entity: Entity) -> BoundEntity<'w> {
+        BoundEntity::new(entity, self.world)
+    }
+
+    /// Gets a `BoundEntity` only if the entity exists.
+    ///
+    /// Returns `None` if the entity has been despawned.
+    #[inline]
+    #[must_use]
+    pub fn entity_opt(&self, entity: Entity) -> Option<BoundEntity<'w>> {
+        if self.world.get_entity(entity).is_ok() {
+            Some(BoundEntity::new(entity, self.world))
+        } else {
+            None
+        }
+    }
+
+    /// Creates a `BoundEntity` from an `EntityHandle`.
+    #[inline]
+    pub fn from_handle(&self, handle: EntityHandle) -> BoundEntity<'w> {
+        handle.bind(self.world)
+    }
+
+    /// Gets a component directly from an entity.
+    ///
+    /// Convenience method for one-off component access without creating a `BoundEntity`.
+    #[inline]
+    pub fn get<T: Component>(&self, entity: Entity) -> Option<&'w T> {
+        self.world.get::<T>(entity)
+    }
+
+    /// Returns the underlying World reference.
+    #[inline]
+    pub const fn world(&self) -> &'w World {
+        self.world
+    }
+}
+
+impl std::fmt::Debug for WorldScope<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WorldScope").finish_non_exhaustive()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::WorldExt;
 
     #[derive(Component)]
     struct Name(&'static str);
 
+    impl Named for Name {
+        fn name(&self) -> &str {
+            self.0
+        }
+    }
+
     #[derive(Component)]
     struct Health(i32);
 
@@ -258,6 +1037,9 @@ mod tests {
     #[derive(Component)]
     struct OptionalTarget(Option<EntityHandle>);
 
+    #[derive(Component, Clone)]
+    struct Team(Vec<EntityHandle>);
+
     #[test]
     fn entity_handle_is_send_sync() {
         fn assert_send_sync<T: Send + Sync>() {}
@@ -284,6 +1066,52 @@ mod tests {
         assert_eq!(entity, entity2);
     }
 
+    #[test]
+    fn handle_checked_new() {
+        assert!(EntityHandle::checked_new(Entity::PLACEHOLDER).is_none());
+        assert_eq!(
+            EntityHandle::new(Entity::PLACEHOLDER).entity(),
+            Entity::PLACEHOLDER
+        );
+
+        let mut world = World::new();
+        let entity = world.spawn_empty().id();
+        let handle = EntityHandle::checked_new(entity).unwrap();
+        assert_eq!(handle, EntityHandle::new(entity));
+        assert!(handle.is_alive(&world));
+    }
+
+    #[test]
+    fn handle_as_ptr() {
+        let mut world = World::new();
+        let entity = world.spawn(Health(12)).id();
+        let handle = EntityHandle::new(entity);
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let ptr = handle.as_ptr(world_ref);
+        assert_eq!(ptr, world_ref.from_handle(handle));
+        assert_eq!(ptr.get::<Health>().unwrap().0, 12);
+    }
+
+    #[test]
+    fn handle_entity_comparison() {
+        let entity = Entity::from_raw_u32(42).unwrap();
+        let other = Entity::from_raw_u32(7).unwrap();
+        let handle = EntityHandle::new(entity);
+
+        assert!(handle.equals_entity(entity));
+        assert!(!handle.equals_entity(other));
+        assert!(handle == entity);
+        assert!(entity == handle);
+        assert!(handle != other);
+        assert!(other != handle);
+
+        // Filtering raw query results against stored handles
+        let attackers = [EntityHandle::new(other), handle];
+        assert_eq!(attackers.iter().filter(|&&h| h == entity).count(), 1);
+    }
+
     #[test]
     fn handle_component_access() {
         let mut world = World::new();
@@ -313,6 +1141,290 @@ mod tests {
         assert!(handle.get::<Name>(&world).is_none());
     }
 
+    #[test]
+    fn bound_entity_follow_get() {
+        let mut world = World::new();
+        let target = world.spawn(Health(25)).id();
+        let source = world
+            .spawn((Name("source"), Target(EntityHandle::new(target))))
+            .id();
+
+        let bound = world.bind_entity(source);
+        let (link, health) = bound.follow_get::<Target, Health, _>(|t| t.0).unwrap();
+        assert_eq!(link.0.entity(), target);
+        assert_eq!(health.0, 25);
+        assert!(
+            world
+                .bind_entity(target)
+                .follow_get::<Target, Health, _>(|t| t.0)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn bound_entity_get_ref2() {
+        use bevy_ecs::change_detection::DetectChanges;
+
+        let mut world = World::new();
+        let entity = world.spawn((Name("tracked"), Health(10))).id();
+        world.clear_trackers();
+        world.get_mut::<Name>(entity).unwrap().0 = "renamed";
+
+        let (name, health) = world
+            .bind_entity(entity)
+            .get_ref2::<Name, Health>()
+            .unwrap();
+        assert!(name.is_changed());
+        assert!(!health.is_changed());
+        assert!(world.bind_entity(entity).get_ref2::<Name, Team>().is_none());
+    }
+
+    #[test]
+    fn bound_entity_name_via() {
+        let mut world = World::new();
+        let named = world.spawn(Name("alice")).id();
+        let unnamed = world.spawn(Health(5)).id();
+
+        assert_eq!(world.bind_entity(named).name_via::<Name>(), Some("alice"));
+        assert_eq!(world.bind_entity(unnamed).name_via::<Name>(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "missing hero: entity")]
+    fn bound_entity_expect_alive_panics_when_despawned() {
+        let mut world = World::new();
+        let entity = world.spawn(Health(1)).id();
+        assert!(
+            EntityHandle::new(entity)
+                .bind(&world)
+                .expect_alive("missing hero")
+                .is_alive()
+        );
+        world.despawn(entity);
+        let _ = EntityHandle::new(entity)
+            .bind(&world)
+            .expect_alive("missing hero");
+    }
+
+    #[test]
+    fn bound_entity_and_then_if_alive() {
+        let mut world = World::new();
+        let alive = world.spawn(Health(5)).id();
+        let gone = world.spawn(Health(1)).id();
+        world.despawn(gone);
+
+        let health = |e| {
+            world
+                .bind_entity(e)
+                .and_then_if_alive(|b| b.get::<Health>())
+        };
+        assert_eq!(health(alive).map(|h| h.0), Some(5));
+        assert!(health(gone).is_none());
+    }
+
+    #[test]
+    fn bound_entity_location() {
+        let mut world = World::new();
+        let entity = world.spawn(Name("here")).id();
+        let gone = world.spawn(Name("gone")).id();
+        world.despawn(gone);
+
+        let location = world.bind_entity(entity).location().unwrap();
+        assert_eq!(location, world.entity(entity).location());
+        assert!(world.bind_entity(gone).location().is_none());
+    }
+
+    #[test]
+    fn bound_entity_same_archetype() {
+        let mut world = World::new();
+        let a = world.spawn((Name("a"), Health(1))).id();
+        let b = world.spawn((Name("b"), Health(2))).id();
+        let c = world.spawn(Health(3)).id();
+        let gone = world.spawn((Name("gone"), Health(4))).id();
+        world.despawn(gone);
+
+        let a = world.bind_entity(a);
+        assert!(a.same_archetype(world.bind_entity(b)));
+        assert!(!a.same_archetype(world.bind_entity(c)));
+        assert!(!world.bind_entity(gone).same_archetype(a));
+
+        // The first archetype spawned in another world can share `a`'s archetype ID
+        let mut other = World::new();
+        let foreign = other.spawn(Health(5)).id();
+        assert!(!a.same_archetype(other.bind_entity(foreign)));
+        assert!(!other.bind_entity(foreign).same_archetype(a));
+    }
+
+    #[test]
+    fn bound_entity_component_count() {
+        let mut world = World::new();
+        let entity = world
+            .spawn((
+                Name("a"),
+                Health(1),
+                Target(EntityHandle::new(Entity::PLACEHOLDER)),
+            ))
+            .id();
+        let gone = world.spawn(Name("gone")).id();
+        world.despawn(gone);
+
+        assert_eq!(world.bind_entity(entity).component_count(), 3);
+        assert_eq!(world.bind_entity(gone).component_count(), 0);
+    }
+
+    #[test]
+    fn handle_debug_resolve() {
+        let mut world = World::new();
+        let hero = world.spawn(Name("hero")).id();
+        let nameless = world.spawn(Health(1)).id();
+        let handle = EntityHandle::new(hero);
+
+        assert_eq!(
+            handle.debug_resolve::<Name>(&world),
+            format!("EntityHandle({hero}) [Live: Name=\"hero\"]")
+        );
+        assert_eq!(
+            EntityHandle::new(nameless).debug_resolve::<Name>(&world),
+            format!("EntityHandle({nameless}) [Live]")
+        );
+
+        world.despawn(hero);
+        assert_eq!(
+            handle.debug_resolve::<Name>(&world),
+            format!("EntityHandle({hero}) [Despawned]")
+        );
+    }
+
+    #[test]
+    fn handle_follow_without_binding() {
+        let mut world = World::new();
+        let c = world.spawn(Name("c")).id();
+        let b = world.spawn(Target(EntityHandle::new(c))).id();
+        let a = world.spawn(Target(EntityHandle::new(b))).id();
+        let empty = world.spawn(OptionalTarget(None)).id();
+        let linked = world.spawn(OptionalTarget(Some(EntityHandle::new(a)))).id();
+
+        let hop = |h: EntityHandle| h.follow::<Target, _>(&world, |t| t.0);
+        let end = hop(EntityHandle::new(a)).and_then(hop);
+        assert_eq!(end, Some(EntityHandle::new(c)));
+        assert_eq!(end.and_then(hop), None);
+
+        let opt = |e| EntityHandle::new(e).follow_opt::<OptionalTarget, _>(&world, |t| t.0);
+        assert_eq!(opt(linked), Some(EntityHandle::new(a)));
+        assert_eq!(opt(empty), None);
+        assert_eq!(opt(c), None);
+    }
+
+    #[test]
+    fn handle_get2_get3() {
+        let mut world = World::new();
+        let full = world
+            .spawn((Name("full"), Health(7), Team(Vec::new())))
+            .id();
+        let partial = world.spawn((Name("partial"), Health(3))).id();
+
+        let (name, health) = EntityHandle::new(full)
+            .get2::<Name, Health>(&world)
+            .unwrap();
+        assert_eq!((name.0, health.0), ("full", 7));
+        let (name, health, team) = EntityHandle::new(full)
+            .get3::<Name, Health, Team>(&world)
+            .unwrap();
+        assert_eq!((name.0, health.0), ("full", 7));
+        assert!(team.0.is_empty());
+
+        let partial = EntityHandle::new(partial);
+        assert!(partial.get2::<Name, Health>(&world).is_some());
+        assert!(partial.get2::<Name, Team>(&world).is_none());
+        assert!(partial.get3::<Name, Health, Team>(&world).is_none());
+
+        world.despawn(full);
+        assert!(
+            EntityHandle::new(full)
+                .get2::<Name, Health>(&world)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn snapshot_still_valid() {
+        let mut world = World::new();
+        let entity = world.spawn(Name("snap")).id();
+        let gone = world.spawn(Name("gone")).id();
+        world.despawn(gone);
+
+        let snapshot = world.bind_entity(entity).snapshot().unwrap();
+        assert_eq!(snapshot.entity(), entity);
+        assert_eq!(snapshot.handle(), EntityHandle::new(entity));
+        assert!(snapshot.still_valid(&world));
+        assert!(world.bind_entity(gone).snapshot().is_none());
+
+        world.despawn(entity);
+        assert!(!snapshot.still_valid(&world));
+
+        // A recycled index is a different entity
+        let recycled = world.spawn(Name("recycled")).id();
+        assert_eq!(recycled.index(), entity.index());
+        assert!(!snapshot.still_valid(&world));
+    }
+
+    #[test]
+    fn typed_handle_basics() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<TypedEntityHandle<Name>>();
+        assert_eq!(std::mem::size_of::<TypedEntityHandle<Name>>(), 8);
+
+        let mut world = World::new();
+        let named = world.spawn(Name("typed")).id();
+        let unnamed = world.spawn(Health(1)).id();
+
+        let typed = TypedEntityHandle::<Name>::new(named);
+        assert_eq!(typed.entity(), named);
+        assert_eq!(EntityHandle::from(typed), EntityHandle::new(named));
+        assert_eq!(typed.get(&world).unwrap().0, "typed");
+        assert!(
+            TypedEntityHandle::<Name>::new(unnamed)
+                .get(&world)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn handle_container_shapes() {
+        fn collect(container: &(impl HandleContainer + ?Sized)) -> Vec<EntityHandle> {
+            let mut out = Vec::new();
+            container.for_each_handle(|h| out.push(h));
+            out
+        }
+
+        let a = EntityHandle::new(Entity::from_raw_u32(1).unwrap());
+        let b = EntityHandle::new(Entity::from_raw_u32(2).unwrap());
+
+        assert_eq!(collect(&a), vec![a]);
+        assert_eq!(collect(&Some(b)), vec![b]);
+        assert!(collect(&None::<EntityHandle>).is_empty());
+        assert_eq!(collect(&vec![a, b, a]), vec![a, b, a]);
+        assert_eq!(collect(&[b, a][..]), vec![b, a]);
+    }
+
+    #[test]
+    fn handle_vec_retain_alive() {
+        let mut world = World::new();
+        let a = world.spawn(Name("a")).id();
+        let b = world.spawn(Name("b")).id();
+        let c = world.spawn(Name("c")).id();
+        let mut handles: Vec<_> = [a, b, c, b].into_iter().map(EntityHandle::new).collect();
+
+        world.despawn(b);
+
+        let alive = handles.alive_handles(&world);
+        assert_eq!(alive, vec![EntityHandle::new(a), EntityHandle::new(c)]);
+        assert_eq!(handles.len(), 4, "alive_handles must not mutate");
+
+        handles.retain_alive(&world);
+        assert_eq!(handles, alive);
+    }
+
     #[test]
     fn bound_entity_access() {
         let mut world = World::new();
@@ -327,6 +1439,88 @@ mod tests {
         assert!(bound.is_alive());
     }
 
+    #[test]
+    fn bound_entity_get2_and_get_map() {
+        let mut world = World::new();
+        let hero = world.spawn((Name("hero"), Health(10))).id();
+        let ghost = world.spawn(Name("ghost")).id();
+
+        let (name, health) = world.bind_entity(hero).get2::<Name, Health>().unwrap();
+        assert_eq!((name.0, health.0), ("hero", 10));
+        assert!(world.bind_entity(ghost).get2::<Name, Health>().is_none());
+
+        // The mapped borrow lives as long as the world borrow
+        let name: Option<&str> = world.bind_entity(hero).get_map::<Name, _, _>(|n| n.0);
+        assert_eq!(name, Some("hero"));
+        assert_eq!(
+            world
+                .bind_entity(ghost)
+                .get_map::<Health, _, _>(|h| h.0 * 2),
+            None
+        );
+    }
+
+    #[test]
+    fn bound_entity_get_cloned_and_filter() {
+        let mut world = World::new();
+        let hero = world.spawn((Name("hero"), Team(Vec::new()))).id();
+        let target = world.spawn((Name("target"), Health(0))).id();
+        let source = world.spawn(Target(EntityHandle::new(target))).id();
+
+        let team: Option<Team> = world.bind_entity(hero).get_cloned::<Team>();
+        assert!(team.unwrap().0.is_empty());
+        assert!(world.bind_entity(source).get_cloned::<Team>().is_none());
+
+        let dead = world
+            .bind_entity(source)
+            .follow::<Target, _>(|t| t.0)
+            .and_then(|t| t.filter(|t| t.get::<Health>().is_some_and(|h| h.0 <= 0)));
+        assert_eq!(dead.map(|d| d.entity()), Some(target));
+        assert!(
+            world
+                .bind_entity(hero)
+                .filter(|b| b.has::<Health>())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn bound_entity_get_slice() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let team = world
+            .spawn(Team(vec![EntityHandle::new(a), EntityHandle::new(b)]))
+            .id();
+
+        let bound = EntityHandle::new(team).bind(&world);
+        let members = bound.get_slice::<Team, _, _>(|s| &s.0).unwrap();
+        assert_eq!(members, [EntityHandle::new(a), EntityHandle::new(b)]);
+        assert!(
+            EntityHandle::new(a)
+                .bind(&world)
+                .get_slice::<Team, _, _>(|s| &s.0)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn bound_entity_zip_get() {
+        let mut world = World::new();
+        let a = world.spawn(Health(10)).id();
+        let b = world.spawn(Health(12)).id();
+        let c = world.spawn(Name("c")).id();
+
+        let a = EntityHandle::new(a).bind(&world);
+        let b = EntityHandle::new(b).bind(&world);
+        let c = EntityHandle::new(c).bind(&world);
+
+        let (ha, hb) = a.zip_get::<Health>(b).unwrap();
+        assert_eq!((ha.0, hb.0), (10, 12));
+        assert!(a.zip_get::<Health>(c).is_none());
+        assert!(c.zip_get::<Health>(a).is_none());
+    }
+
     #[test]
     fn bound_entity_follow() {
         let mut world = World::new();
@@ -361,6 +1555,113 @@ mod tests {
         assert!(without.follow_opt::<OptionalTarget, _>(|t| t.0).is_none());
     }
 
+    #[test]
+    fn bound_entity_follow_opt_or() {
+        let mut world = World::new();
+        let root = world.spawn(Name("root")).id();
+        let target_entity = world.spawn(Name("target")).id();
+        let with_target = world
+            .spawn(OptionalTarget(Some(EntityHandle::new(target_entity))))
+            .id();
+        let without_target = world.spawn(OptionalTarget(None)).id();
+
+        let follow = |e| {
+            world
+                .bind_entity(e)
+                .follow_opt_or::<OptionalTarget, _>(|t| t.0, root)
+                .get::<Name>()
+                .unwrap()
+                .0
+        };
+        assert_eq!(follow(with_target), "target");
+        assert_eq!(follow(without_target), "root");
+    }
+
+    #[test]
+    fn bound_entity_follow_many() {
+        let mut world = World::new();
+        let alice = world.spawn(Name("alice")).id();
+        let bob = world.spawn(Name("bob")).id();
+        let team = world
+            .spawn(Team(vec![EntityHandle::new(alice), EntityHandle::new(bob)]))
+            .id();
+        let loner = world.spawn(Name("loner")).id();
+
+        let team = EntityHandle::new(team).bind(&world);
+        let names: Vec<_> = team
+            .follow_many::<Team, _>(|t| &t.0)
+            .map(|m| m.get::<Name>().unwrap().0)
+            .collect();
+        assert_eq!(names, vec!["alice", "bob"]);
+
+        let loner = EntityHandle::new(loner).bind(&world);
+        assert_eq!(loner.follow_many::<Team, _>(|t| &t.0).count(), 0);
+    }
+
+    #[test]
+    fn bound_entity_iter_ext_filters() {
+        let mut world = World::new();
+        let alice = world.spawn((Name("alice"), Health(10))).id();
+        let bob = world.spawn(Name("bob")).id();
+        let gone = world.spawn(Health(1)).id();
+        let team = world
+            .spawn(Team([gone, alice, bob].map(EntityHandle::new).to_vec()))
+            .id();
+        world.despawn(gone);
+
+        let team = world.bind_entity(team);
+        assert_eq!(team.follow_many::<Team, _>(|t| &t.0).alive().count(), 2);
+        let healthy: Vec<_> = team
+            .follow_many::<Team, _>(|t| &t.0)
+            .with_component::<Health>()
+            .map(|b| b.entity())
+            .collect();
+        assert_eq!(healthy, vec![alice]);
+    }
+
+    #[test]
+    fn bound_entity_follow_handle() {
+        let mut world = World::new();
+        let alice = world.spawn(Name("alice")).id();
+        let bob = world.spawn(Name("bob")).id();
+        let team = world
+            .spawn(Team(vec![EntityHandle::new(alice), EntityHandle::new(bob)]))
+            .id();
+
+        let team = world.bind_entity(team);
+        let names: Vec<_> = team
+            .get::<Team>()
+            .unwrap()
+            .0
+            .iter()
+            .map(|&h| team.follow_handle(h).get::<Name>().unwrap().0)
+            .collect();
+        assert_eq!(names, vec!["alice", "bob"]);
+        assert!(std::ptr::eq(
+            team.follow_handle(EntityHandle::new(bob)).world(),
+            &world
+        ));
+    }
+
+    #[test]
+    fn bound_entity_follow_many_unique() {
+        let mut world = World::new();
+        let alice = world.spawn(Name("alice")).id();
+        let bob = world.spawn(Name("bob")).id();
+        let team = world
+            .spawn(Team(
+                [alice, alice, bob, alice].map(EntityHandle::new).to_vec(),
+            ))
+            .id();
+
+        let names: Vec<_> = world
+            .bind_entity(team)
+            .follow_many_unique::<Team, _>(|t| &t.0)
+            .map(|m| m.get::<Name>().unwrap().0)
+            .collect();
+        assert_eq!(names, vec!["alice", "bob"]);
+    }
+
     #[test]
     fn bound_entity_stale() {
         let mut world = World::new();
@@ -397,12 +1698,47 @@ mod tests {
         assert_eq!(b1, b1_copy);
         assert_ne!(b1, b2);
 
+        #[allow(clippy::mutable_key_type)]
         let mut set = std::collections::HashSet::new();
         set.insert(b1);
         assert!(set.contains(&b1_copy));
         assert!(!set.contains(&b2));
     }
 
+    #[test]
+    fn world_scope_access() {
+        let mut world = World::new();
+        let target = world.spawn((Name("target"), Health(7))).id();
+        let source = world
+            .spawn((Name("source"), Target(EntityHandle::new(target))))
+            .id();
+        let fake = Entity::from_raw_u32(9999).unwrap();
+
+        let scope = crate::WorldRef::new_scoped(&world);
+        let scope2 = scope; // Copy
+
+        let followed = scope.entity(source).follow::<Target, _>(|t| t.0).unwrap();
+        assert_eq!(followed.get::<Name>().unwrap().0, "target");
+        assert_eq!(scope2.get::<Health>(target).unwrap().0, 7);
+        assert_eq!(scope2.from_handle(EntityHandle::new(target)), followed);
+        assert!(scope.entity_opt(source).is_some());
+        assert!(scope.entity_opt(fake).is_none());
+        assert_eq!(std::mem::size_of::<WorldScope<'_>>(), 8);
+    }
+
+    #[test]
+    fn world_scope_results_outlive_scope_value() {
+        let mut world = World::new();
+        let entity = world.spawn(Name("kept")).id();
+
+        // References are tied to the world borrow, not to the scope value itself.
+        let name = {
+            let scope = WorldScope::new(&world);
+            scope.entity(entity).get::<Name>().unwrap()
+        };
+        assert_eq!(name.0, "kept");
+    }
+
     #[test]
     fn nav_wrapper_access() {
         let mut world = World::new();
@@ -90,7 +90,12 @@
 //!
 //! ## Feature Flags
 //!
-//! - `nav-traits`: Enables `HasParent` and `HasChildren` traits for parent/child navigation
+//! - `nav-traits`: Enables `HasParent`, `HasParents` and `HasChildren` traits for parent/child navigation
+//! - `commands`: Enables `SpawnChildExt` for spawning a child and recording its handle in one step
+//! - `serde`: Enables `EntityPtrNavMany::export_tree()` for JSON subtree dumps (implies `nav-traits`)
+//! - `dot`: Enables `EntityPtrNavMany::to_dot()` for Graphviz subtree dumps (implies `nav-traits`)
+//! - `reflect`: Enables `EntityPtr::for_each_component_reflect()` for reflective component views
+//! - `derive`: Enables `#[derive(HasChildren)]` and `#[derive(HasParent)]` (implies `nav-traits`)
 //!
 //! ## Design Principles
 //!
@@ -120,19 +125,58 @@
 //! For fully safe code with no soundness caveats, use [`EntityHandle`] and
 //! [`BoundEntity`] instead — they carry proper lifetime parameters.
 
+#[cfg(feature = "nav-traits")]
+mod arena;
+mod debug;
+#[cfg(feature = "dot")]
+mod dot;
+mod error;
+#[cfg(feature = "serde")]
+mod export;
+mod guard;
 mod handle;
+mod macros;
 mod ptr;
+#[cfg(feature = "reflect")]
+mod reflect;
+mod snapshot;
 
 #[cfg(feature = "nav-traits")]
 mod nav;
+#[cfg(feature = "commands")]
+mod spawn;
 
 // Core types - always available
-pub use handle::{BoundEntity, BoundEntityNav, EntityHandle};
-pub use ptr::{EntityPtr, EntityPtrNav, EntityPtrNavMany, WorldRef};
+pub use debug::{DebugComponents, DebugEntity};
+pub use error::EntityAccessError;
+pub use guard::GuardedPtr;
+pub use handle::{
+    BoundEntity, BoundEntityIterExt, BoundEntityNav, EntityHandle, EntitySnapshot, HandleContainer,
+    HandleSliceExt, Named, TypedEntityHandle, WorldScope,
+};
+pub use ptr::{
+    EntityPtr, EntityPtrIterExt, EntityPtrNav, EntityPtrNavMany, WorldRef, WorldScopedPtr,
+    shortest_hops, sort_by_component,
+};
+pub use snapshot::CloneComponents;
 
 // Navigation traits - feature-gated
 #[cfg(feature = "nav-traits")]
-pub use nav::{HasChildren, HasParent};
+pub use arena::PtrArena;
+#[cfg(feature = "nav-traits")]
+pub use nav::{
+    ChildDiff, HasChildren, HasParent, HasParents, common_root, diff_children, union_children,
+};
+
+// Hierarchy construction helpers - feature-gated
+#[cfg(feature = "commands")]
+pub use spawn::SpawnChildExt;
+
+// Derive macros for the navigation traits - feature-gated
+#[cfg(feature = "derive")]
+pub use bevy_entity_ptr_derive::{HasChildren, HasParent};
+
+use std::borrow::Borrow;
 
 use bevy_ecs::entity::Entity;
 use bevy_ecs::world::World;
@@ -183,6 +227,31 @@ pub trait WorldExt {
     /// For fully safe code without this invariant, use
     /// [`EntityHandle::bind()`] and [`BoundEntity`] instead.
     fn entity_ptr(&self, entity: Entity) -> EntityPtr;
+
+    /// Creates a `WorldScope` guard that keeps the real lifetime of the World.
+    ///
+    /// Offers `EntityPtr`-style traversal without the `unsafe` invariant: every
+    /// entity from the guard is a `BoundEntity<'_>`, so the borrow checker rejects
+    /// dropping or mutating the World while it is in use.
+    ///
+    /// ```compile_fail
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_entity_ptr::WorldExt;
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.spawn_empty().id();
+    ///
+    /// let bound = world.world_guard().entity(entity);
+    /// drop(world); // error: cannot move out of `world` because it is borrowed
+    /// assert!(bound.is_alive());
+    /// ```
+    #[inline]
+    fn world_guard(&self) -> WorldScope<'_>
+    where
+        Self: Borrow<World>,
+    {
+        WorldScope::new(self.borrow())
+    }
 }
 
 impl WorldExt for World {
@@ -883,6 +952,23 @@ mod integration_tests {
         assert_eq!(bound.get::<Name>().unwrap().0, "test");
     }
 
+    /// Test WorldExt::world_guard access.
+    #[test]
+    fn world_scope_guard_access() {
+        let mut world = World::new();
+        let target = world.spawn(Name("target")).id();
+        let entity = world
+            .spawn((Name("test"), Parent(EntityHandle::new(target))))
+            .id();
+
+        let guard = world.world_guard();
+        assert_eq!(guard.entity(entity).get::<Name>().unwrap().0, "test");
+
+        let followed = guard.entity(entity).follow::<Parent, _>(|p| p.0).unwrap();
+        assert_eq!(followed.get::<Name>().unwrap().0, "target");
+        assert_eq!(followed.entity(), guard.entity(target).entity());
+    }
+
     /// Test EntityPtr Eq and Hash implementations.
     #[test]
     fn entity_ptr_eq_hash() {
@@ -899,6 +985,7 @@ mod integration_tests {
         assert_ne!(ptr1, ptr2);
 
         // Test works in HashSet
+        #[allow(clippy::mutable_key_type)]
         let mut set = std::collections::HashSet::new();
         set.insert(ptr1);
         assert!(set.contains(&ptr1_copy));
@@ -915,6 +1002,9 @@ mod nav_integration_tests {
     #[derive(Component)]
     struct Name(&'static str);
 
+    #[derive(Component)]
+    struct Health(i32);
+
     #[derive(Component)]
     struct ParentRef(Option<EntityHandle>);
 
@@ -933,6 +1023,62 @@ mod nav_integration_tests {
         }
     }
 
+    /// Breadth traversal over a wide tree, collecting grandchildren in one call.
+    #[test]
+    fn breadth_traversal_flat_map_children() {
+        let mut world = World::new();
+
+        // Build wide tree:
+        //           root
+        //    /    /    \    \
+        //   a    b      c    d
+        //  /\   /\     /\   /\
+        // a0 a1 b0 b1 c0 c1 d0 d1
+        let branches: Vec<_> = [
+            ("a", ["a0", "a1"]),
+            ("b", ["b0", "b1"]),
+            ("c", ["c0", "c1"]),
+            ("d", ["d0", "d1"]),
+        ]
+        .into_iter()
+        .map(|(branch, leaf_names)| {
+            let leaves = leaf_names
+                .map(|leaf| EntityHandle::new(world.spawn(Name(leaf)).id()))
+                .to_vec();
+            EntityHandle::new(world.spawn((Name(branch), ChildRefs(leaves))).id())
+        })
+        .collect();
+        let root = world.spawn((Name("root"), ChildRefs(branches))).id();
+
+        // SAFETY: world outlives usage
+        let w = unsafe { WorldRef::new(&world) };
+        let grandchildren: Vec<_> = w
+            .entity(root)
+            .nav_many()
+            .flat_map_children::<ChildRefs, _, _, _>(|c| c.nav_many().children::<ChildRefs>())
+            .collect();
+
+        assert_eq!(grandchildren.len(), 8);
+        let names: Vec<_> = grandchildren
+            .iter()
+            .filter_map(|p| p.get::<Name>())
+            .map(|n| n.0)
+            .collect();
+        assert_eq!(names, vec!["a0", "a1", "b0", "b1", "c0", "c1", "d0", "d1"]);
+
+        let bound_names: Vec<_> = world
+            .bind_entity(root)
+            .nav()
+            .flat_map_children::<ChildRefs, _, _, _>(|c| {
+                c.nav()
+                    .children::<ChildRefs>()
+                    .filter_map(|g| g.get::<Name>())
+            })
+            .map(|n| n.0)
+            .collect();
+        assert_eq!(bound_names, names);
+    }
+
     /// Test parent navigation with BoundEntity.
     #[test]
     fn bound_parent_chain() {
@@ -994,4 +1140,45 @@ mod nav_integration_tests {
         assert!(names.contains(&"child2"));
         assert!(names.contains(&"child3"));
     }
+
+    /// Test the filter_map_over_children pattern via filter_map_children.
+    #[test]
+    fn filter_map_children_healths() {
+        let mut world = World::new();
+
+        // Some children have Health, some don't
+        let healthy1 = world.spawn((Name("healthy1"), Health(50))).id();
+        let healthy2 = world.spawn((Name("healthy2"), Health(75))).id();
+        let no_health1 = world.spawn(Name("no_health1")).id();
+        let no_health2 = world.spawn(Name("no_health2")).id();
+
+        let parent = world
+            .spawn((
+                Name("parent"),
+                ChildRefs(vec![
+                    EntityHandle::new(healthy1),
+                    EntityHandle::new(no_health1),
+                    EntityHandle::new(healthy2),
+                    EntityHandle::new(no_health2),
+                ]),
+            ))
+            .id();
+
+        let ptr = world.entity_ptr(parent);
+        let healths: Vec<i32> = ptr
+            .nav_many()
+            .filter_map_children::<ChildRefs, _, _>(|c| c.get::<Health>().map(|h| h.0))
+            .collect();
+        assert_eq!(healths, vec![50, 75]);
+        assert_eq!(healths.iter().sum::<i32>(), 125);
+
+        let bound = world.bind_entity(parent);
+        let names: Vec<&str> = bound
+            .nav()
+            .filter_map_children::<ChildRefs, _, _>(|c| {
+                c.has::<Health>().then(|| c.get::<Name>().unwrap().0)
+            })
+            .collect();
+        assert_eq!(names, vec!["healthy1", "healthy2"]);
+    }
 }
@@ -0,0 +1,139 @@
+//! Macros for navigation patterns that span heterogeneous component types.
+
+/// Follows the first of several reference components that is present.
+///
+/// Expands to a chain of `follow` calls joined with `Option::or_else`, trying each
+/// `Component => extractor` pair in order and returning the first successful
+/// follow. Works with `EntityPtr` and `BoundEntity`. Useful when a link may be
+/// stored under one of several relationship components.
+///
+/// # Example
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_entity_ptr::{EntityHandle, WorldExt, follow_any};
+///
+/// #[derive(Component)]
+/// struct Owner(EntityHandle);
+///
+/// #[derive(Component)]
+/// struct Holder(EntityHandle);
+///
+/// let mut world = World::new();
+/// let player = world.spawn_empty().id();
+/// let item = world.spawn(Holder(EntityHandle::new(player))).id();
+///
+/// let ptr = world.entity_ptr(item);
+/// let keeper = follow_any!(ptr, Owner => |o| o.0, Holder => |h| h.0);
+/// assert_eq!(keeper.map(|k| k.entity()), Some(player));
+/// ```
+#[macro_export]
+macro_rules! follow_any {
+    ($ptr:expr, $($component:ty => $extract:expr),+ $(,)?) => {{
+        let ptr = $ptr;
+        ::core::option::Option::None
+            $(.or_else(|| ptr.follow::<$component, _>($extract)))+
+    }};
+}
+
+/// Checks several components at once, yielding a tuple of presence flags.
+///
+/// Expands to `(ptr.has::<A>(), ptr.has::<B>(), ...)`, one `bool` per listed type
+/// in order, so the result reads as a single destructuring. Works with `EntityPtr`
+/// and `BoundEntity`; a despawned entity yields all `false`.
+///
+/// # Example
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_entity_ptr::{WorldExt, component_flags};
+///
+/// #[derive(Component)]
+/// struct Health(i32);
+///
+/// #[derive(Component)]
+/// struct Armor;
+///
+/// let mut world = World::new();
+/// let entity = world.spawn(Health(10)).id();
+///
+/// let (has_health, has_armor) = component_flags!(world.entity_ptr(entity), Health, Armor);
+/// assert!(has_health && !has_armor);
+/// ```
+#[macro_export]
+macro_rules! component_flags {
+    ($ptr:expr, $($component:ty),+ $(,)?) => {{
+        let ptr = $ptr;
+        ($(ptr.has::<$component>(),)+)
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{EntityHandle, WorldExt};
+    use bevy_ecs::component::Component;
+    use bevy_ecs::world::World;
+
+    #[derive(Component)]
+    struct Owner(EntityHandle);
+
+    #[derive(Component)]
+    struct Holder(EntityHandle);
+
+    #[derive(Component)]
+    struct Health;
+
+    #[derive(Component)]
+    struct Armor;
+
+    #[derive(Component)]
+    struct Shield;
+
+    #[test]
+    fn follow_any_first_present_wins() {
+        let mut world = World::new();
+        let owner = world.spawn_empty().id();
+        let holder = world.spawn_empty().id();
+        let owned = world.spawn(Owner(EntityHandle::new(owner))).id();
+        let held = world.spawn(Holder(EntityHandle::new(holder))).id();
+        let both = world
+            .spawn((
+                Owner(EntityHandle::new(owner)),
+                Holder(EntityHandle::new(holder)),
+            ))
+            .id();
+        let neither = world.spawn_empty().id();
+
+        let resolve = |e| {
+            follow_any!(world.entity_ptr(e), Owner => |o| o.0, Holder => |h| h.0)
+                .map(|p| p.entity())
+        };
+        assert_eq!(resolve(owned), Some(owner));
+        assert_eq!(resolve(held), Some(holder));
+        assert_eq!(resolve(both), Some(owner));
+        assert_eq!(resolve(neither), None);
+
+        // Order of the pairs decides precedence; also works on BoundEntity
+        let bound = follow_any!(world.bind_entity(both), Holder => |h| h.0, Owner => |o| o.0);
+        assert_eq!(bound.map(|b| b.entity()), Some(holder));
+    }
+
+    #[test]
+    fn component_flags_subset() {
+        let mut world = World::new();
+        let entity = world.spawn((Health, Shield)).id();
+
+        let (has_health, has_armor, has_shield) =
+            component_flags!(world.entity_ptr(entity), Health, Armor, Shield);
+        assert!(has_health);
+        assert!(!has_armor);
+        assert!(has_shield);
+
+        let (has_armor,) = component_flags!(world.bind_entity(entity), Armor);
+        assert!(!has_armor);
+
+        world.despawn(entity);
+        assert_eq!(
+            component_flags!(world.bind_entity(entity), Health, Shield),
+            (false, false)
+        );
+    }
+}
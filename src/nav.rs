@@ -3,6 +3,14 @@
 //! This module provides traits for components that define entity relationships.
 //! Feature-gated behind `nav-traits`.
 
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::world::World;
+
 use crate::handle::EntityHandle;
 
 /// Trait for components that reference a parent entity.
@@ -51,6 +59,353 @@ pub trait HasChildren {
     fn children_handles(&self) -> &[EntityHandle];
 }
 
+/// Trait for components that reference several parent entities.
+///
+/// Generalizes `HasParent` to DAGs where a node can have more than one parent,
+/// e.g. a scene-instancing graph. Implement this to enable `.nav_many().parents()`.
+///
+/// # Example
+/// ```no_run
+/// use bevy_ecs::prelude::*;
+/// use bevy_entity_ptr::{EntityHandle, HasParents};
+///
+/// #[derive(Component)]
+/// struct InstancedFrom(Vec<EntityHandle>);
+///
+/// impl HasParents for InstancedFrom {
+///     fn parent_handles(&self) -> &[EntityHandle] {
+///         &self.0
+///     }
+/// }
+/// ```
+pub trait HasParents {
+    /// Returns a slice of handles to parent entities.
+    fn parent_handles(&self) -> &[EntityHandle];
+}
+
+// Shared traversal machinery. Walks operate on raw `Entity` + `&World` so that
+// the BoundEntity and EntityPtr navigators wrap the same logic.
+
+/// Returns the child handles stored in `T` on `entity`, or an empty slice.
+#[inline]
+fn child_handles<T: Component + HasChildren>(world: &World, entity: Entity) -> &[EntityHandle] {
+    world
+        .get::<T>(entity)
+        .map(HasChildren::children_handles)
+        .unwrap_or(&[])
+}
+
+/// Pre-order depth-first walk over the descendants of a root entity (root excluded).
+///
+/// Uses an explicit stack rather than recursion. Assumes the hierarchy is acyclic.
+struct Descendants<'w, T> {
+    world: &'w World,
+    stack: Vec<Entity>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'w, T: Component + HasChildren> Descendants<'w, T> {
+    fn new(world: &'w World, root: Entity) -> Self {
+        let mut walk = Self {
+            world,
+            stack: Vec::new(),
+            _marker: PhantomData,
+        };
+        walk.push_children(root);
+        walk
+    }
+
+    /// Pushes children in reverse so they pop in their stored order.
+    #[inline]
+    fn push_children(&mut self, entity: Entity) {
+        self.stack.extend(
+            child_handles::<T>(self.world, entity)
+                .iter()
+                .rev()
+                .map(|h| h.entity()),
+        );
+    }
+}
+
+impl<T: Component + HasChildren> Iterator for Descendants<'_, T> {
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Entity> {
+        let entity = self.stack.pop()?;
+        self.push_children(entity);
+        Some(entity)
+    }
+}
+
+/// Post-order depth-first walk over the descendants of a root entity (root excluded).
+///
+/// Each stack entry records whether its children were already pushed; an entity is
+/// yielded the second time it is popped. Assumes the hierarchy is acyclic.
+struct DescendantsPostOrder<'w, T> {
+    world: &'w World,
+    stack: Vec<(Entity, bool)>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'w, T: Component + HasChildren> DescendantsPostOrder<'w, T> {
+    fn new(world: &'w World, root: Entity) -> Self {
+        let mut walk = Self {
+            world,
+            stack: Vec::new(),
+            _marker: PhantomData,
+        };
+        walk.push_children(root);
+        walk
+    }
+
+    #[inline]
+    fn push_children(&mut self, entity: Entity) {
+        self.stack.extend(
+            child_handles::<T>(self.world, entity)
+                .iter()
+                .rev()
+                .map(|h| (h.entity(), false)),
+        );
+    }
+}
+
+impl<T: Component + HasChildren> Iterator for DescendantsPostOrder<'_, T> {
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Entity> {
+        loop {
+            let (entity, expanded) = self.stack.pop()?;
+            if expanded {
+                return Some(entity);
+            }
+            self.stack.push((entity, true));
+            self.push_children(entity);
+        }
+    }
+}
+
+/// Pre-order depth-first walk that stops descending below `max_depth`.
+///
+/// Immediate children are depth 1. Assumes the hierarchy is acyclic.
+struct DescendantsWithin<'w, T> {
+    world: &'w World,
+    stack: Vec<(Entity, usize)>,
+    max_depth: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'w, T: Component + HasChildren> DescendantsWithin<'w, T> {
+    fn new(world: &'w World, root: Entity, max_depth: usize) -> Self {
+        let mut walk = Self {
+            world,
+            stack: Vec::new(),
+            max_depth,
+            _marker: PhantomData,
+        };
+        walk.push_children(root, 1);
+        walk
+    }
+
+    #[inline]
+    fn push_children(&mut self, entity: Entity, depth: usize) {
+        if depth > self.max_depth {
+            return;
+        }
+        self.stack.extend(
+            child_handles::<T>(self.world, entity)
+                .iter()
+                .rev()
+                .map(|h| (h.entity(), depth)),
+        );
+    }
+}
+
+impl<T: Component + HasChildren> Iterator for DescendantsWithin<'_, T> {
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Entity> {
+        let (entity, depth) = self.stack.pop()?;
+        self.push_children(entity, depth + 1);
+        Some(entity)
+    }
+}
+
+/// Counts the nodes at each depth of a subtree (root at depth 0), breadth-first.
+///
+/// Walks one level at a time, swapping two frontier buffers. Assumes the hierarchy
+/// is acyclic.
+fn level_sizes<T: Component + HasChildren>(world: &World, root: Entity) -> Vec<usize> {
+    let mut sizes = Vec::new();
+    let mut level = vec![root];
+    let mut next = Vec::new();
+    while !level.is_empty() {
+        sizes.push(level.len());
+        next.extend(
+            level
+                .iter()
+                .flat_map(|&e| child_handles::<T>(world, e))
+                .map(|h| h.entity()),
+        );
+        level.clear();
+        std::mem::swap(&mut level, &mut next);
+    }
+    sizes
+}
+
+/// Collects a subtree into raw entities, optionally including the root first.
+fn collect_descendant_entities<T: Component + HasChildren>(
+    world: &World,
+    root: Entity,
+    include_self: bool,
+) -> Vec<Entity> {
+    let mut out = Vec::new();
+    if include_self {
+        out.push(root);
+    }
+    out.extend(Descendants::<T>::new(world, root));
+    out
+}
+
+/// Yields a `(parent, child)` pair for every link in the subtree under `root`.
+///
+/// Parents are visited in depth-first pre-order (root first), each emitting its
+/// children in stored order. Assumes the hierarchy is acyclic.
+fn subtree_edges<T: Component + HasChildren>(
+    world: &World,
+    root: Entity,
+) -> impl Iterator<Item = (Entity, Entity)> + '_ {
+    std::iter::once(root)
+        .chain(Descendants::<T>::new(world, root))
+        .flat_map(move |parent| {
+            child_handles::<T>(world, parent)
+                .iter()
+                .map(move |h| (parent, h.entity()))
+        })
+}
+
+/// Finds the path from `root` to the first descendant matching `pred`, breadth-first.
+///
+/// The root is included in the path but never tested. Parent links are recorded
+/// as nodes are discovered and followed back once a match is found; entities are
+/// visited at most once, so cycles cannot hang the search.
+fn find_path_entities<T, F>(world: &World, root: Entity, mut pred: F) -> Option<Vec<Entity>>
+where
+    T: Component + HasChildren,
+    F: FnMut(Entity) -> bool,
+{
+    let mut came_from: HashMap<Entity, Entity> = HashMap::new();
+    let mut queue = VecDeque::from([root]);
+    while let Some(node) = queue.pop_front() {
+        for child in child_handles::<T>(world, node).iter().map(|h| h.entity()) {
+            if child == root || came_from.contains_key(&child) {
+                continue;
+            }
+            came_from.insert(child, node);
+            if pred(child) {
+                let mut path = vec![child];
+                let mut current = child;
+                while let Some(&parent) = came_from.get(&current) {
+                    path.push(parent);
+                    current = parent;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            queue.push_back(child);
+        }
+    }
+    None
+}
+
+/// Collects the descendants of `root` level by level (root excluded).
+///
+/// Each level keeps its children in stored order. Assumes the hierarchy is acyclic.
+fn collect_descendant_entities_bfs<T: Component + HasChildren>(
+    world: &World,
+    root: Entity,
+) -> Vec<Entity> {
+    let mut out: Vec<Entity> = child_handles::<T>(world, root)
+        .iter()
+        .map(|h| h.entity())
+        .collect();
+    let mut next = 0;
+    while let Some(&entity) = out.get(next) {
+        out.extend(child_handles::<T>(world, entity).iter().map(|h| h.entity()));
+        next += 1;
+    }
+    out
+}
+
+/// Returns the parent of `entity` via `P`, if any.
+#[inline]
+fn parent_entity<P: Component + HasParent>(world: &World, entity: Entity) -> Option<Entity> {
+    world
+        .get::<P>(entity)
+        .and_then(HasParent::parent_handle)
+        .map(|h| h.entity())
+}
+
+/// Checks that `entity`'s parent (via `P`) lists `entity` among its children (via `C`).
+fn is_linked_both_ways<P, C>(world: &World, entity: Entity) -> bool
+where
+    P: Component + HasParent,
+    C: Component + HasChildren,
+{
+    parent_entity::<P>(world, entity).is_some_and(|p| {
+        child_handles::<C>(world, p)
+            .iter()
+            .any(|h| h.entity() == entity)
+    })
+}
+
+/// Walk up the parent chain (via `P`) from a start entity (start excluded).
+///
+/// Cycle-safe: tracks visited entities and stops before revisiting one, so a
+/// corrupted hierarchy ends the walk instead of looping forever.
+struct Ancestors<'w, P> {
+    world: &'w World,
+    current: Entity,
+    visited: HashSet<Entity>,
+    _marker: PhantomData<fn() -> P>,
+}
+
+impl<'w, P: Component + HasParent> Ancestors<'w, P> {
+    fn new(world: &'w World, start: Entity) -> Self {
+        Self {
+            world,
+            current: start,
+            visited: HashSet::from([start]),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<P: Component + HasParent> Iterator for Ancestors<'_, P> {
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Entity> {
+        let parent = parent_entity::<P>(self.world, self.current)?;
+        if !self.visited.insert(parent) {
+            return None;
+        }
+        self.current = parent;
+        Some(parent)
+    }
+}
+
+/// Iterates the other children (via `C`) of `entity`'s parent (via `P`).
+fn sibling_entities<'w, P, C>(world: &'w World, entity: Entity) -> impl Iterator<Item = Entity> + 'w
+where
+    P: Component + HasParent,
+    C: Component + HasChildren,
+{
+    parent_entity::<P>(world, entity)
+        .map_or(&[][..], |p| child_handles::<C>(world, p))
+        .iter()
+        .map(|h| h.entity())
+        .filter(move |&e| e != entity)
+}
+
 // Extension implementations for BoundEntity navigation
 
 use crate::handle::{BoundEntity, BoundEntityNav};
@@ -67,210 +422,2770 @@ impl<'w> BoundEntityNav<'w> {
         })
     }
 
-    /// Navigates to child entities using a component that implements `HasChildren`.
+    /// Returns `true` if this entity has no parent via `T`.
     ///
-    /// Returns an iterator of `BoundEntity` for each child. Returns an empty
-    /// iterator if the component is missing.
+    /// See [`EntityPtrNav::is_root`].
     #[inline]
-    pub fn children<T: bevy_ecs::component::Component + HasChildren>(
+    pub fn is_root<T: Component + HasParent>(self) -> bool {
+        parent_entity::<T>(self.0.world(), self.0.entity()).is_none()
+    }
+
+    /// Walks up the parent chain, nearest ancestor first (this entity excluded).
+    ///
+    /// Cycle-safe: the walk ends before any entity would be yielded twice.
+    #[inline]
+    pub fn ancestors<P: Component + HasParent>(self) -> impl Iterator<Item = BoundEntity<'w>> + 'w {
+        let world = self.0.world();
+        Ancestors::<P>::new(world, self.0.entity()).map(move |e| BoundEntity::new(e, world))
+    }
+
+    /// Returns the first ancestor for which `stop` returns `true`.
+    ///
+    /// This entity is not tested. Returns `None` if the root is reached first.
+    #[inline]
+    pub fn ancestor_until<P, F>(self, mut stop: F) -> Option<BoundEntity<'w>>
+    where
+        P: Component + HasParent,
+        F: FnMut(BoundEntity<'w>) -> bool,
+    {
+        self.ancestors::<P>().find(|&a| stop(a))
+    }
+
+    /// Walks up the parent chain, pairing each ancestor with its hop count.
+    ///
+    /// See [`EntityPtrNav::ancestors_enumerated`].
+    #[inline]
+    pub fn ancestors_enumerated<P: Component + HasParent>(
         self,
-    ) -> impl Iterator<Item = BoundEntity<'w>> + 'w {
-        self.0.get::<T>().into_iter().flat_map(move |c| {
-            c.children_handles()
-                .iter()
-                .copied()
-                .map(move |h| BoundEntity::new(h.entity(), self.0.world()))
-        })
+    ) -> impl Iterator<Item = (usize, BoundEntity<'w>)> + 'w {
+        (1..).zip(self.ancestors::<P>())
     }
-}
 
-// Extension implementations for EntityPtr navigation
+    /// Returns the ancestor `n` hops up (`0` is this entity, `1` the parent).
+    ///
+    /// See [`EntityPtrNav::ancestor_at`].
+    #[inline]
+    #[must_use]
+    pub fn ancestor_at<P: Component + HasParent>(self, n: usize) -> Option<BoundEntity<'w>> {
+        match n {
+            0 => Some(self.0),
+            _ => self.ancestors::<P>().nth(n - 1),
+        }
+    }
 
-use crate::ptr::{EntityPtr, EntityPtrNav, EntityPtrNavMany};
+    /// Gets `T` from this entity, or else from the nearest ancestor that has it.
+    ///
+    /// Ancestors are found via `P`; see [`EntityPtrNav::get_inherited`].
+    #[inline]
+    #[must_use]
+    pub fn get_inherited<T, P>(self) -> Option<&'w T>
+    where
+        T: Component,
+        P: Component + HasParent,
+    {
+        self.0
+            .get::<T>()
+            .or_else(|| self.ancestors::<P>().find_map(|a| a.get::<T>()))
+    }
 
-impl EntityPtrNav {
-    /// Navigates to the parent entity using a component that implements `HasParent`.
+    /// Iterates the other children of this entity's parent.
     ///
-    /// Returns `None` if this entity doesn't have the component or has no parent.
+    /// The parent is found via `P` and its children via `C`; this entity is skipped.
+    /// Returns an empty iterator if there is no parent.
     #[inline]
-    pub fn parent<T: bevy_ecs::component::Component + HasParent>(self) -> Option<EntityPtr> {
-        self.0.get::<T>().and_then(|c| {
-            c.parent_handle()
-                .map(|h| EntityPtr::new(h.entity(), self.0.world()))
-        })
+    pub fn siblings<P, C>(self) -> impl Iterator<Item = BoundEntity<'w>> + 'w
+    where
+        P: Component + HasParent,
+        C: Component + HasChildren,
+    {
+        let world = self.0.world();
+        sibling_entities::<P, C>(world, self.0.entity()).map(move |e| BoundEntity::new(e, world))
+    }
+
+    /// Counts the other children of this entity's parent, or 0 if there is no parent.
+    #[inline]
+    pub fn siblings_count<P, C>(self) -> usize
+    where
+        P: Component + HasParent,
+        C: Component + HasChildren,
+    {
+        sibling_entities::<P, C>(self.0.world(), self.0.entity()).count()
+    }
+
+    /// Checks whether this entity has a parent with no other children.
+    #[inline]
+    pub fn is_only_child<P, C>(self) -> bool
+    where
+        P: Component + HasParent,
+        C: Component + HasChildren,
+    {
+        parent_entity::<P>(self.0.world(), self.0.entity()).is_some()
+            && self.siblings_count::<P, C>() == 0
+    }
+
+    /// Checks that this entity's parent (via `P`) lists it among its children (via `C`).
+    ///
+    /// Returns `false` if there is no parent or the parent lacks `C`.
+    #[inline]
+    pub fn verify_bidirectional<P, C>(self) -> bool
+    where
+        P: Component + HasParent,
+        C: Component + HasChildren,
+    {
+        is_linked_both_ways::<P, C>(self.0.world(), self.0.entity())
+    }
+
+    /// Returns `true` if this entity has no children via `T`.
+    ///
+    /// See [`EntityPtrNavMany::is_leaf`].
+    #[inline]
+    pub fn is_leaf<T: Component + HasChildren>(self) -> bool {
+        child_handles::<T>(self.0.world(), self.0.entity()).is_empty()
+    }
+
+    /// Iterates the immediate children, pairing each stored handle with its entity.
+    ///
+    /// See [`EntityPtrNavMany::children_with_handles`].
+    #[inline]
+    pub fn children_with_handles<T: Component + HasChildren>(
+        self,
+    ) -> impl Iterator<Item = (EntityHandle, BoundEntity<'w>)> {
+        let world = self.0.world();
+        child_handles::<T>(world, self.0.entity())
+            .iter()
+            .map(move |&h| (h, BoundEntity::new(h.entity(), world)))
+    }
+
+    /// Collects component `C` from each direct child that has it.
+    ///
+    /// See [`EntityPtrNavMany::collect_children_component`].
+    #[must_use]
+    pub fn collect_children_component<T, C>(self) -> Vec<&'w C>
+    where
+        T: Component + HasChildren,
+        C: Component,
+    {
+        let world = self.0.world();
+        child_handles::<T>(world, self.0.entity())
+            .iter()
+            .filter_map(|h| world.get::<C>(h.entity()))
+            .collect()
     }
-}
 
-impl EntityPtrNavMany {
     /// Navigates to child entities using a component that implements `HasChildren`.
     ///
-    /// Returns an iterator of `EntityPtr` for each child. Returns an empty
+    /// Returns an iterator of `BoundEntity` for each child. Returns an empty
     /// iterator if the component is missing.
     #[inline]
     pub fn children<T: bevy_ecs::component::Component + HasChildren>(
         self,
-    ) -> impl Iterator<Item = EntityPtr> {
+    ) -> impl Iterator<Item = BoundEntity<'w>> + 'w {
         self.0.get::<T>().into_iter().flat_map(move |c| {
             c.children_handles()
                 .iter()
                 .copied()
-                .map(move |h| EntityPtr::new(h.entity(), self.0.world()))
+                .map(move |h| BoundEntity::new(h.entity(), self.0.world()))
         })
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::ptr::WorldRef;
-    use bevy_ecs::component::Component;
-    use bevy_ecs::world::World;
 
-    #[derive(Component)]
-    struct Name(&'static str);
+    /// Navigates to every parent listed by a component implementing `HasParents`.
+    ///
+    /// See [`EntityPtrNavMany::parents`].
+    #[inline]
+    pub fn parents<T: Component + HasParents>(self) -> impl Iterator<Item = BoundEntity<'w>> + 'w {
+        let world = self.0.world();
+        self.0
+            .get::<T>()
+            .map(HasParents::parent_handles)
+            .unwrap_or(&[])
+            .iter()
+            .map(move |h| BoundEntity::new(h.entity(), world))
+    }
 
-    #[derive(Component)]
-    struct ParentRef(Option<EntityHandle>);
+    /// Follows `L`'s reference, then iterates the target's children via `C`.
+    ///
+    /// See [`EntityPtrNavMany::follow_then_children`].
+    pub fn follow_then_children<L, C, F>(self, f: F) -> impl Iterator<Item = BoundEntity<'w>> + 'w
+    where
+        L: Component,
+        C: Component + HasChildren,
+        F: FnOnce(&L) -> EntityHandle,
+    {
+        self.0
+            .follow::<L, F>(f)
+            .into_iter()
+            .flat_map(|target| target.nav().children::<C>())
+    }
 
-    impl HasParent for ParentRef {
-        fn parent_handle(&self) -> Option<EntityHandle> {
-            self.0
-        }
+    /// Maps each child through `f`, keeping only the `Some` results.
+    ///
+    /// Shorthand for `.children::<T>().filter_map(f)`.
+    #[inline]
+    pub fn filter_map_children<T, R, F>(self, f: F) -> impl Iterator<Item = R>
+    where
+        T: Component + HasChildren,
+        F: FnMut(BoundEntity<'w>) -> Option<R>,
+    {
+        self.children::<T>().filter_map(f)
     }
 
-    #[derive(Component)]
-    struct ChildRefs(Vec<EntityHandle>);
+    /// Maps each child to several values through `f` and flattens the results.
+    ///
+    /// Shorthand for `.children::<T>().flat_map(f)`.
+    #[inline]
+    pub fn flat_map_children<T, R, I, F>(self, f: F) -> impl Iterator<Item = R>
+    where
+        T: Component + HasChildren,
+        I: IntoIterator<Item = R>,
+        F: FnMut(BoundEntity<'w>) -> I,
+    {
+        self.children::<T>().flat_map(f)
+    }
 
-    impl HasChildren for ChildRefs {
-        fn children_handles(&self) -> &[EntityHandle] {
-            &self.0
+    /// Buckets the immediate children by the key `key` computes for each one.
+    ///
+    /// Children keep their stored order within each bucket.
+    pub fn group_children_by<T, K, F>(self, mut key: F) -> HashMap<K, Vec<BoundEntity<'w>>>
+    where
+        T: Component + HasChildren,
+        K: Eq + Hash,
+        F: FnMut(BoundEntity<'w>) -> K,
+    {
+        let mut groups: HashMap<K, Vec<BoundEntity<'w>>> = HashMap::new();
+        for child in self.children::<T>() {
+            groups.entry(key(child)).or_default().push(child);
         }
+        groups
     }
 
-    #[test]
-    fn bound_entity_nav_parent() {
-        let mut world = World::new();
-        let parent = world.spawn(Name("parent")).id();
-        let child = world
-            .spawn((Name("child"), ParentRef(Some(EntityHandle::new(parent)))))
-            .id();
-
-        let bound = EntityHandle::new(child).bind(&world);
-        let parent_bound = bound.nav().parent::<ParentRef>().unwrap();
-
-        assert_eq!(parent_bound.get::<Name>().unwrap().0, "parent");
+    /// Splits the immediate children into those matching `pred` and the rest.
+    ///
+    /// See [`EntityPtrNavMany::partition_children`].
+    #[inline]
+    pub fn partition_children<T, P>(self, pred: P) -> (Vec<BoundEntity<'w>>, Vec<BoundEntity<'w>>)
+    where
+        T: Component + HasChildren,
+        P: FnMut(&BoundEntity<'w>) -> bool,
+    {
+        self.children::<T>().partition(pred)
     }
 
-    #[test]
-    fn bound_entity_nav_parent_none() {
-        let mut world = World::new();
-        let orphan = world.spawn((Name("orphan"), ParentRef(None))).id();
-
-        let bound = EntityHandle::new(orphan).bind(&world);
-        assert!(bound.nav().parent::<ParentRef>().is_none());
+    /// Returns the child whose `V` component yields the smallest key.
+    ///
+    /// Children without `V` are skipped. On ties the first such child wins.
+    pub fn min_child_by_key<T, V, K, F>(self, mut key: F) -> Option<BoundEntity<'w>>
+    where
+        T: Component + HasChildren,
+        V: Component,
+        K: Ord,
+        F: FnMut(&V) -> K,
+    {
+        self.children::<T>()
+            .filter_map(|c| c.get::<V>().map(|v| (key(v), c)))
+            .min_by(|a, b| a.0.cmp(&b.0))
+            .map(|(_, c)| c)
     }
 
-    #[test]
-    fn bound_entity_nav_children() {
-        let mut world = World::new();
-        let child1 = world.spawn(Name("child1")).id();
-        let child2 = world.spawn(Name("child2")).id();
-        let parent = world
-            .spawn((
-                Name("parent"),
-                ChildRefs(vec![EntityHandle::new(child1), EntityHandle::new(child2)]),
-            ))
-            .id();
+    /// Returns the child whose `V` component yields the largest key.
+    ///
+    /// Children without `V` are skipped. On ties the last such child wins.
+    pub fn max_child_by_key<T, V, K, F>(self, mut key: F) -> Option<BoundEntity<'w>>
+    where
+        T: Component + HasChildren,
+        V: Component,
+        K: Ord,
+        F: FnMut(&V) -> K,
+    {
+        self.children::<T>()
+            .filter_map(|c| c.get::<V>().map(|v| (key(v), c)))
+            .max_by(|a, b| a.0.cmp(&b.0))
+            .map(|(_, c)| c)
+    }
 
-        let bound = EntityHandle::new(parent).bind(&world);
-        let children: Vec<_> = bound.nav().children::<ChildRefs>().collect();
+    /// Reduces the immediate children to one by repeatedly applying `f`.
+    ///
+    /// Returns `None` if there are no children or the component is missing.
+    #[inline]
+    pub fn reduce_children<T, F>(self, f: F) -> Option<BoundEntity<'w>>
+    where
+        T: Component + HasChildren,
+        F: FnMut(BoundEntity<'w>, BoundEntity<'w>) -> BoundEntity<'w>,
+    {
+        self.children::<T>().reduce(f)
+    }
 
-        assert_eq!(children.len(), 2);
-        let names: Vec<_> = children
+    /// Returns the position of `target` among the immediate children.
+    ///
+    /// Compares by `Entity`. Returns `None` if `target` isn't a child or the
+    /// component is missing.
+    #[inline]
+    #[must_use]
+    pub fn index_of_child<T: Component + HasChildren>(self, target: Entity) -> Option<usize> {
+        child_handles::<T>(self.0.world(), self.0.entity())
             .iter()
-            .map(|c| c.get::<Name>().unwrap().0)
-            .collect();
-        assert!(names.contains(&"child1"));
-        assert!(names.contains(&"child2"));
+            .position(|h| h.entity() == target)
+    }
+
+    /// Folds over the immediate children, threading an accumulator through `f`.
+    ///
+    /// Only one level is visited. Returns `init` if the component is missing.
+    #[inline]
+    pub fn fold_children<T, A, F>(self, init: A, f: F) -> A
+    where
+        T: Component + HasChildren,
+        F: FnMut(A, BoundEntity<'w>) -> A,
+    {
+        self.children::<T>().fold(init, f)
+    }
+
+    /// Folds the `V` components of the immediate children into one value.
+    ///
+    /// See [`EntityPtrNavMany::reduce_children_component`].
+    #[inline]
+    pub fn reduce_children_component<T, V, A, F>(self, init: A, f: F) -> A
+    where
+        T: Component + HasChildren,
+        V: Component,
+        F: FnMut(A, &V) -> A,
+    {
+        let world = self.0.world();
+        child_handles::<T>(world, self.0.entity())
+            .iter()
+            .filter_map(|h| world.get::<V>(h.entity()))
+            .fold(init, f)
+    }
+
+    /// Walks every descendant of this entity in depth-first pre-order.
+    ///
+    /// The entity itself is not yielded. The hierarchy formed by `T` must be acyclic;
+    /// a cycle makes the iterator run forever.
+    #[inline]
+    pub fn descendants<T: Component + HasChildren>(
+        self,
+    ) -> impl Iterator<Item = BoundEntity<'w>> + 'w {
+        let world = self.0.world();
+        Descendants::<T>::new(world, self.0.entity()).map(move |e| BoundEntity::new(e, world))
+    }
+
+    /// Collects `extract(v)` for each node's `V` in this subtree, in pre-order.
+    ///
+    /// This entity is included first; nodes without `V` are skipped. See
+    /// [`EntityPtrNavMany::collect_field_preorder`].
+    pub fn collect_field_preorder<T, V, R, F>(self, mut extract: F) -> Vec<R>
+    where
+        T: Component + HasChildren,
+        V: Component,
+        F: FnMut(&V) -> R,
+    {
+        self.subtree::<T>()
+            .filter_map(|node| node.get::<V>().map(&mut extract))
+            .collect()
+    }
+
+    /// Maps each node of this subtree (this entity included) to `f(node)`.
+    ///
+    /// Shared nodes are computed and inserted once. See
+    /// [`EntityPtrNavMany::collect_subtree_map`].
+    pub fn collect_subtree_map<T, V, F>(self, mut f: F) -> HashMap<Entity, V>
+    where
+        T: Component + HasChildren,
+        F: FnMut(BoundEntity<'w>) -> V,
+    {
+        let mut map = HashMap::new();
+        for node in self.subtree::<T>() {
+            map.entry(node.entity()).or_insert_with(|| f(node));
+        }
+        map
+    }
+
+    /// Returns the number of nodes at each depth of this subtree.
+    ///
+    /// Index `i` counts the nodes at depth `i`; this entity is depth 0, so the first
+    /// entry is always 1. See [`EntityPtrNavMany::level_sizes`].
+    #[must_use]
+    pub fn level_sizes<T: Component + HasChildren>(self) -> Vec<usize> {
+        level_sizes::<T>(self.0.world(), self.0.entity())
+    }
+
+    /// Walks every descendant of this entity in depth-first post-order.
+    ///
+    /// Children come before their parent; the entity itself is not yielded. See
+    /// [`EntityPtrNavMany::descendants_post_order`].
+    #[inline]
+    pub fn descendants_post_order<T: Component + HasChildren>(
+        self,
+    ) -> impl Iterator<Item = BoundEntity<'w>> + 'w {
+        let world = self.0.world();
+        DescendantsPostOrder::<T>::new(world, self.0.entity())
+            .map(move |e| BoundEntity::new(e, world))
+    }
+
+    /// Walks this entity and then its descendants in depth-first pre-order.
+    ///
+    /// Same as `descendants()` with this entity yielded first.
+    #[inline]
+    pub fn subtree<T: Component + HasChildren>(self) -> impl Iterator<Item = BoundEntity<'w>> + 'w {
+        std::iter::once(self.0).chain(self.descendants::<T>())
+    }
+
+    /// Walks the descendants at most `max_depth` levels below this entity, in pre-order.
+    ///
+    /// Immediate children are depth 1, so `max_depth == 0` yields nothing. See
+    /// [`EntityPtrNavMany::descendants_within`].
+    #[inline]
+    pub fn descendants_within<T: Component + HasChildren>(
+        self,
+        max_depth: usize,
+    ) -> impl Iterator<Item = BoundEntity<'w>> + 'w {
+        let world = self.0.world();
+        DescendantsWithin::<T>::new(world, self.0.entity(), max_depth)
+            .map(move |e| BoundEntity::new(e, world))
+    }
+
+    /// Counts the descendants of this entity for which `pred` returns `true`.
+    ///
+    /// The entity itself is not considered. Matches are counted during the walk
+    /// without collecting the descendants.
+    pub fn count_descendants_where<T, P>(self, mut pred: P) -> usize
+    where
+        T: Component + HasChildren,
+        P: FnMut(BoundEntity<'w>) -> bool,
+    {
+        self.descendants::<T>().filter(|&d| pred(d)).count()
+    }
+
+    /// Appends every descendant of this entity to `out`, in depth-first pre-order.
+    ///
+    /// Existing contents of `out` are kept, and its capacity is reused instead of
+    /// allocating a fresh `Vec`; see [`EntityPtrNavMany::extend_descendants`] for
+    /// the buffer reuse pattern.
+    pub fn extend_descendants<T: Component + HasChildren>(self, out: &mut Vec<BoundEntity<'w>>) {
+        out.extend(self.descendants::<T>());
+    }
+
+    /// Collects the raw `Entity` IDs of this entity's subtree in depth-first pre-order.
+    ///
+    /// When `include_self` is `true` this entity comes first. See
+    /// [`EntityPtrNavMany::descendant_entities`] for the despawn use case.
+    pub fn descendant_entities<T: Component + HasChildren>(
+        self,
+        include_self: bool,
+    ) -> Vec<Entity> {
+        collect_descendant_entities::<T>(self.0.world(), self.0.entity(), include_self)
+    }
+
+    /// Collects the raw `Entity` IDs of this entity's descendants in depth-first pre-order.
+    ///
+    /// See [`EntityPtrNavMany::descendants_entities_dfs`].
+    pub fn descendants_entities_dfs<T: Component + HasChildren>(self) -> Vec<Entity> {
+        self.descendant_entities::<T>(false)
+    }
+
+    /// Collects the raw `Entity` IDs of this entity's descendants in breadth-first order.
+    ///
+    /// See [`EntityPtrNavMany::descendants_entities_bfs`].
+    pub fn descendants_entities_bfs<T: Component + HasChildren>(self) -> Vec<Entity> {
+        collect_descendant_entities_bfs::<T>(self.0.world(), self.0.entity())
+    }
+
+    /// Collects `EntityHandle`s for this entity's subtree in depth-first pre-order.
+    ///
+    /// Like `descendant_entities()` but returns storable handles. See
+    /// [`EntityPtrNavMany::descendant_handles`].
+    pub fn descendant_handles<T: Component + HasChildren>(
+        self,
+        include_self: bool,
+    ) -> Vec<EntityHandle> {
+        self.descendant_entities::<T>(include_self)
+            .into_iter()
+            .map(EntityHandle::new)
+            .collect()
+    }
+
+    /// Returns the path from this entity to the first descendant matching `pred`.
+    ///
+    /// See [`EntityPtrNavMany::find_path_to`].
+    pub fn find_path_to<T, P>(self, mut pred: P) -> Option<Vec<BoundEntity<'w>>>
+    where
+        T: Component + HasChildren,
+        P: FnMut(BoundEntity<'w>) -> bool,
+    {
+        let world = self.0.world();
+        let path = find_path_entities::<T, _>(world, self.0.entity(), |e| {
+            pred(BoundEntity::new(e, world))
+        })?;
+        Some(
+            path.into_iter()
+                .map(|e| BoundEntity::new(e, world))
+                .collect(),
+        )
+    }
+
+    /// Yields a `(parent, child)` pair for every link in this entity's subtree.
+    ///
+    /// See [`EntityPtrNavMany::child_edges`].
+    pub fn child_edges<T: Component + HasChildren>(
+        self,
+    ) -> impl Iterator<Item = (Entity, Entity)> + 'w {
+        subtree_edges::<T>(self.0.world(), self.0.entity())
+    }
+}
+
+// Extension implementations for EntityPtr navigation
+
+use crate::ptr::{EntityPtr, EntityPtrNav, EntityPtrNavMany};
+
+impl EntityPtrNav {
+    /// Navigates to the parent entity using a component that implements `HasParent`.
+    ///
+    /// Returns `None` if this entity doesn't have the component or has no parent.
+    #[inline]
+    pub fn parent<T: bevy_ecs::component::Component + HasParent>(self) -> Option<EntityPtr> {
+        self.0.get::<T>().and_then(|c| {
+            c.parent_handle()
+                .map(|h| EntityPtr::new(h.entity(), self.0.world()))
+        })
+    }
+
+    /// Returns `true` if this entity has no parent via `T`.
+    ///
+    /// True when the `T` component is missing or its `parent_handle()` is `None`;
+    /// reads better than `.parent::<T>().is_none()` when classifying nodes.
+    #[inline]
+    pub fn is_root<T: Component + HasParent>(self) -> bool {
+        parent_entity::<T>(self.0.world(), self.0.entity()).is_none()
+    }
+
+    /// Walks up the parent chain, nearest ancestor first (this entity excluded).
+    ///
+    /// Cycle-safe: visited entities are tracked and the walk ends before any
+    /// entity would be yielded twice, so corrupted parent links cannot hang it.
+    #[inline]
+    pub fn ancestors<P: Component + HasParent>(self) -> impl Iterator<Item = EntityPtr> {
+        let world = self.0.world();
+        Ancestors::<P>::new(world, self.0.entity()).map(move |e| EntityPtr::new(e, world))
+    }
+
+    /// Returns the first ancestor for which `stop` returns `true`.
+    ///
+    /// This entity is not tested. Returns `None` if the root is reached first, e.g.
+    /// finding the enclosing `Room` of an item with `|a| a.has::<Room>()`. Inherits
+    /// the cycle safety of `ancestors()`.
+    #[inline]
+    pub fn ancestor_until<P, F>(self, mut stop: F) -> Option<EntityPtr>
+    where
+        P: Component + HasParent,
+        F: FnMut(EntityPtr) -> bool,
+    {
+        self.ancestors::<P>().find(|&a| stop(a))
+    }
+
+    /// Walks up the parent chain, pairing each ancestor with its hop count.
+    ///
+    /// Yields `(1, parent)`, `(2, grandparent)` and so on, which helps when the
+    /// walk must be capped, e.g. `.take_while(|(hops, _)| *hops <= 3)`. Inherits
+    /// the cycle safety of `ancestors()`.
+    #[inline]
+    pub fn ancestors_enumerated<P: Component + HasParent>(
+        self,
+    ) -> impl Iterator<Item = (usize, EntityPtr)> {
+        (1..).zip(self.ancestors::<P>())
+    }
+
+    /// Returns the ancestor `n` hops up (`0` is this entity, `1` the parent).
+    ///
+    /// Returns `None` if the chain is shorter than `n`, e.g. reading a setting that
+    /// always lives on the grandparent with `ancestor_at::<P>(2)`. Inherits the
+    /// cycle safety of `ancestors()`, so a cyclic chain counts as ending once it
+    /// would revisit an entity.
+    #[inline]
+    #[must_use]
+    pub fn ancestor_at<P: Component + HasParent>(self, n: usize) -> Option<EntityPtr> {
+        match n {
+            0 => Some(self.0),
+            _ => self.ancestors::<P>().nth(n - 1),
+        }
+    }
+
+    /// Gets `T` from this entity, or else from the nearest ancestor that has it.
+    ///
+    /// Models CSS-like inheritance, e.g. a UI node using its own `TextColor` if set
+    /// and its closest styled ancestor's otherwise. Ancestors are found via `P` and
+    /// the walk is cycle-safe, like `ancestors()`.
+    #[inline]
+    #[must_use]
+    pub fn get_inherited<T, P>(self) -> Option<&'static T>
+    where
+        T: Component,
+        P: Component + HasParent,
+    {
+        self.0
+            .get::<T>()
+            .or_else(|| self.ancestors::<P>().find_map(|a| a.get::<T>()))
+    }
+
+    /// Iterates the other children of this entity's parent.
+    ///
+    /// The parent is found via `P` and its children via `C`; this entity is skipped.
+    /// Returns an empty iterator if there is no parent.
+    #[inline]
+    pub fn siblings<P, C>(self) -> impl Iterator<Item = EntityPtr>
+    where
+        P: Component + HasParent,
+        C: Component + HasChildren,
+    {
+        let world = self.0.world();
+        sibling_entities::<P, C>(world, self.0.entity()).map(move |e| EntityPtr::new(e, world))
+    }
+
+    /// Counts the other children of this entity's parent, or 0 if there is no parent.
+    #[inline]
+    pub fn siblings_count<P, C>(self) -> usize
+    where
+        P: Component + HasParent,
+        C: Component + HasChildren,
+    {
+        sibling_entities::<P, C>(self.0.world(), self.0.entity()).count()
+    }
+
+    /// Checks whether this entity has a parent with no other children.
+    ///
+    /// Useful for layout decisions such as hiding separators around a single child.
+    #[inline]
+    pub fn is_only_child<P, C>(self) -> bool
+    where
+        P: Component + HasParent,
+        C: Component + HasChildren,
+    {
+        parent_entity::<P>(self.0.world(), self.0.entity()).is_some()
+            && self.siblings_count::<P, C>() == 0
+    }
+
+    /// Checks that this entity's parent (via `P`) lists it among its children (via `C`).
+    ///
+    /// An integrity check for hand-maintained relationship pairs: a `false` result
+    /// means the two sides have drifted apart. Also `false` if there is no parent or
+    /// the parent lacks `C`.
+    #[inline]
+    pub fn verify_bidirectional<P, C>(self) -> bool
+    where
+        P: Component + HasParent,
+        C: Component + HasChildren,
+    {
+        is_linked_both_ways::<P, C>(self.0.world(), self.0.entity())
+    }
+}
+
+impl EntityPtrNavMany {
+    /// Returns `true` if this entity has no children via `T`.
+    ///
+    /// True when the `T` component is missing or its child list is empty; reads
+    /// better than `.children::<T>().next().is_none()` when classifying nodes.
+    #[inline]
+    pub fn is_leaf<T: Component + HasChildren>(self) -> bool {
+        child_handles::<T>(self.0.world(), self.0.entity()).is_empty()
+    }
+
+    /// Iterates the immediate children, pairing each stored handle with its pointer.
+    ///
+    /// The handle is the one stored in `T`, not rebuilt from the pointer, which
+    /// helps when writing back a modified child list. Yields nothing if the
+    /// component is missing.
+    #[inline]
+    pub fn children_with_handles<T: Component + HasChildren>(
+        self,
+    ) -> impl Iterator<Item = (EntityHandle, EntityPtr)> {
+        let world = self.0.world();
+        child_handles::<T>(world, self.0.entity())
+            .iter()
+            .map(move |&h| (h, EntityPtr::new(h.entity(), world)))
+    }
+
+    /// Collects component `C` from each direct child that has it.
+    ///
+    /// The materialized form of `.filter_map_children::<T, _, _>(|c| c.get::<C>())`,
+    /// in child order. Children without `C` are skipped; returns an empty `Vec`
+    /// if the `T` component is missing.
+    #[must_use]
+    pub fn collect_children_component<T, C>(self) -> Vec<&'static C>
+    where
+        T: Component + HasChildren,
+        C: Component,
+    {
+        let world = self.0.world();
+        child_handles::<T>(world, self.0.entity())
+            .iter()
+            .filter_map(|h| world.get::<C>(h.entity()))
+            .collect()
+    }
+
+    /// Navigates to child entities using a component that implements `HasChildren`.
+    ///
+    /// Returns an iterator of `EntityPtr` for each child. Returns an empty
+    /// iterator if the component is missing.
+    #[inline]
+    pub fn children<T: bevy_ecs::component::Component + HasChildren>(
+        self,
+    ) -> impl Iterator<Item = EntityPtr> {
+        self.0.get::<T>().into_iter().flat_map(move |c| {
+            c.children_handles()
+                .iter()
+                .copied()
+                .map(move |h| EntityPtr::new(h.entity(), self.0.world()))
+        })
+    }
+
+    /// Navigates to every parent listed by a component implementing `HasParents`.
+    ///
+    /// The multi-parent counterpart of `nav().parent()` for DAG hierarchies.
+    /// Parents are yielded in stored order; returns an empty iterator if the
+    /// component is missing.
+    #[inline]
+    pub fn parents<T: Component + HasParents>(self) -> impl Iterator<Item = EntityPtr> {
+        let world = self.0.world();
+        self.0
+            .get::<T>()
+            .map(HasParents::parent_handles)
+            .unwrap_or(&[])
+            .iter()
+            .map(move |h| EntityPtr::new(h.entity(), world))
+    }
+
+    /// Follows `L`'s reference, then iterates the target's children via `C`.
+    ///
+    /// Composes `EntityPtr::follow` and `children()` for "go to the team, list
+    /// its members" flows. Yields nothing if this entity lacks `L` or the target
+    /// lacks `C`.
+    pub fn follow_then_children<L, C, F>(self, f: F) -> impl Iterator<Item = EntityPtr>
+    where
+        L: Component,
+        C: Component + HasChildren,
+        F: FnOnce(&L) -> EntityHandle,
+    {
+        self.0
+            .follow::<L, F>(f)
+            .into_iter()
+            .flat_map(|target| target.nav_many().children::<C>())
+    }
+
+    /// Maps each child through `f`, keeping only the `Some` results.
+    ///
+    /// Shorthand for `.children::<T>().filter_map(f)`, e.g. collecting the `Health`
+    /// of the children that have one.
+    #[inline]
+    pub fn filter_map_children<T, R, F>(self, f: F) -> impl Iterator<Item = R>
+    where
+        T: Component + HasChildren,
+        F: FnMut(EntityPtr) -> Option<R>,
+    {
+        self.children::<T>().filter_map(f)
+    }
+
+    /// Maps each child to several values through `f` and flattens the results.
+    ///
+    /// Shorthand for `.children::<T>().flat_map(f)`, e.g. collecting grandchildren
+    /// with `|c| c.nav_many().children::<T>()`.
+    #[inline]
+    pub fn flat_map_children<T, R, I, F>(self, f: F) -> impl Iterator<Item = R>
+    where
+        T: Component + HasChildren,
+        I: IntoIterator<Item = R>,
+        F: FnMut(EntityPtr) -> I,
+    {
+        self.children::<T>().flat_map(f)
+    }
+
+    /// Buckets the immediate children by the key `key` computes for each one.
+    ///
+    /// Children keep their stored order within each bucket, e.g. grouping UI
+    /// children by row or category.
+    pub fn group_children_by<T, K, F>(self, mut key: F) -> HashMap<K, Vec<EntityPtr>>
+    where
+        T: Component + HasChildren,
+        K: Eq + Hash,
+        F: FnMut(EntityPtr) -> K,
+    {
+        let mut groups: HashMap<K, Vec<EntityPtr>> = HashMap::new();
+        for child in self.children::<T>() {
+            groups.entry(key(child)).or_default().push(child);
+        }
+        groups
+    }
+
+    /// Splits the immediate children into those matching `pred` and the rest.
+    ///
+    /// Mirrors `Iterator::partition`: the first `Vec` holds the matches, the second
+    /// everything else, both in stored order, e.g. separating visible from hidden UI
+    /// children. Both are empty if the component is missing.
+    #[inline]
+    pub fn partition_children<T, P>(self, pred: P) -> (Vec<EntityPtr>, Vec<EntityPtr>)
+    where
+        T: Component + HasChildren,
+        P: FnMut(&EntityPtr) -> bool,
+    {
+        self.children::<T>().partition(pred)
+    }
+
+    /// Returns the child whose `V` component yields the smallest key.
+    ///
+    /// Children without `V` are skipped, e.g. picking the lowest-`Health` child as a
+    /// target. On ties the first such child wins.
+    pub fn min_child_by_key<T, V, K, F>(self, mut key: F) -> Option<EntityPtr>
+    where
+        T: Component + HasChildren,
+        V: Component,
+        K: Ord,
+        F: FnMut(&V) -> K,
+    {
+        self.children::<T>()
+            .filter_map(|c| c.get::<V>().map(|v| (key(v), c)))
+            .min_by(|a, b| a.0.cmp(&b.0))
+            .map(|(_, c)| c)
+    }
+
+    /// Returns the child whose `V` component yields the largest key.
+    ///
+    /// Children without `V` are skipped. On ties the last such child wins.
+    pub fn max_child_by_key<T, V, K, F>(self, mut key: F) -> Option<EntityPtr>
+    where
+        T: Component + HasChildren,
+        V: Component,
+        K: Ord,
+        F: FnMut(&V) -> K,
+    {
+        self.children::<T>()
+            .filter_map(|c| c.get::<V>().map(|v| (key(v), c)))
+            .max_by(|a, b| a.0.cmp(&b.0))
+            .map(|(_, c)| c)
+    }
+
+    /// Reduces the immediate children to one by repeatedly applying `f`.
+    ///
+    /// `f` picks between two children, e.g. keeping the one with more `Health`.
+    /// Returns `None` if there are no children or the component is missing.
+    #[inline]
+    pub fn reduce_children<T, F>(self, f: F) -> Option<EntityPtr>
+    where
+        T: Component + HasChildren,
+        F: FnMut(EntityPtr, EntityPtr) -> EntityPtr,
+    {
+        self.children::<T>().reduce(f)
+    }
+
+    /// Returns the position of `target` among the immediate children.
+    ///
+    /// Compares by `Entity`. Returns `None` if `target` isn't a child or the
+    /// component is missing.
+    #[inline]
+    #[must_use]
+    pub fn index_of_child<T: Component + HasChildren>(self, target: Entity) -> Option<usize> {
+        child_handles::<T>(self.0.world(), self.0.entity())
+            .iter()
+            .position(|h| h.entity() == target)
+    }
+
+    /// Folds over the immediate children, threading an accumulator through `f`.
+    ///
+    /// Only one level is visited, e.g. summing the `Health` of a squad's members.
+    /// Returns `init` if the component is missing.
+    #[inline]
+    pub fn fold_children<T, A, F>(self, init: A, f: F) -> A
+    where
+        T: Component + HasChildren,
+        F: FnMut(A, EntityPtr) -> A,
+    {
+        self.children::<T>().fold(init, f)
+    }
+
+    /// Folds the `V` components of the immediate children into one value.
+    ///
+    /// Children without `V` are skipped, and nothing is allocated, e.g. totalling
+    /// the `Damage` of all equipped items. Returns `init` if the component is
+    /// missing or no child has `V`.
+    #[inline]
+    pub fn reduce_children_component<T, V, A, F>(self, init: A, f: F) -> A
+    where
+        T: Component + HasChildren,
+        V: Component,
+        F: FnMut(A, &V) -> A,
+    {
+        let world = self.0.world();
+        child_handles::<T>(world, self.0.entity())
+            .iter()
+            .filter_map(|h| world.get::<V>(h.entity()))
+            .fold(init, f)
+    }
+
+    /// Walks every descendant of this entity in depth-first pre-order.
+    ///
+    /// The entity itself is not yielded. The hierarchy formed by `T` must be acyclic;
+    /// a cycle makes the iterator run forever.
+    #[inline]
+    pub fn descendants<T: Component + HasChildren>(self) -> impl Iterator<Item = EntityPtr> {
+        let world = self.0.world();
+        Descendants::<T>::new(world, self.0.entity()).map(move |e| EntityPtr::new(e, world))
+    }
+
+    /// Collects `extract(v)` for each node's `V` in this subtree, in pre-order.
+    ///
+    /// This entity is included first; nodes without `V` are skipped. Replaces the
+    /// hand-written recursive collector, e.g. gathering every `Name` in a tree with
+    /// `collect_field_preorder::<Children, Name, _, _>(|n| n.0)`.
+    pub fn collect_field_preorder<T, V, R, F>(self, mut extract: F) -> Vec<R>
+    where
+        T: Component + HasChildren,
+        V: Component,
+        F: FnMut(&V) -> R,
+    {
+        self.subtree::<T>()
+            .filter_map(|node| node.get::<V>().map(&mut extract))
+            .collect()
+    }
+
+    /// Maps each node of this subtree (this entity included) to `f(node)`.
+    ///
+    /// Builds a lookup table of derived per-entity data during one walk. Nodes
+    /// reachable along several paths (diamonds) are computed and inserted once.
+    pub fn collect_subtree_map<T, V, F>(self, mut f: F) -> HashMap<Entity, V>
+    where
+        T: Component + HasChildren,
+        F: FnMut(EntityPtr) -> V,
+    {
+        let mut map = HashMap::new();
+        for node in self.subtree::<T>() {
+            map.entry(node.entity()).or_insert_with(|| f(node));
+        }
+        map
+    }
+
+    /// Returns the number of nodes at each depth of this subtree.
+    ///
+    /// Index `i` counts the nodes at depth `i`; this entity is depth 0, so the first
+    /// entry is always 1. Handy for spotting unbalanced hierarchies. Computed
+    /// breadth-first; the hierarchy formed by `T` must be acyclic.
+    #[must_use]
+    pub fn level_sizes<T: Component + HasChildren>(self) -> Vec<usize> {
+        level_sizes::<T>(self.0.world(), self.0.entity())
+    }
+
+    /// Walks every descendant of this entity in depth-first post-order.
+    ///
+    /// All descendants of a node come before the node itself, which suits bottom-up
+    /// work such as computing subtree sizes. The entity itself is not yielded. Uses
+    /// an explicit stack; the hierarchy formed by `T` must be acyclic.
+    #[inline]
+    pub fn descendants_post_order<T: Component + HasChildren>(
+        self,
+    ) -> impl Iterator<Item = EntityPtr> {
+        let world = self.0.world();
+        DescendantsPostOrder::<T>::new(world, self.0.entity())
+            .map(move |e| EntityPtr::new(e, world))
+    }
+
+    /// Walks this entity and then its descendants in depth-first pre-order.
+    ///
+    /// Same as `descendants()` with this entity yielded first, for "process this
+    /// node and everything under it".
+    #[inline]
+    pub fn subtree<T: Component + HasChildren>(self) -> impl Iterator<Item = EntityPtr> {
+        std::iter::once(self.0).chain(self.descendants::<T>())
+    }
+
+    /// Walks the descendants at most `max_depth` levels below this entity, in pre-order.
+    ///
+    /// Immediate children are depth 1, so `max_depth == 0` yields nothing and
+    /// `max_depth == 1` matches `children()`. Levels past the cap are never visited,
+    /// which suits incremental or streamed processing of large trees.
+    #[inline]
+    pub fn descendants_within<T: Component + HasChildren>(
+        self,
+        max_depth: usize,
+    ) -> impl Iterator<Item = EntityPtr> {
+        let world = self.0.world();
+        DescendantsWithin::<T>::new(world, self.0.entity(), max_depth)
+            .map(move |e| EntityPtr::new(e, world))
+    }
+
+    /// Counts the descendants of this entity for which `pred` returns `true`.
+    ///
+    /// The entity itself is not considered. Matches are counted during the walk
+    /// without collecting the descendants.
+    pub fn count_descendants_where<T, P>(self, mut pred: P) -> usize
+    where
+        T: Component + HasChildren,
+        P: FnMut(EntityPtr) -> bool,
+    {
+        self.descendants::<T>().filter(|&d| pred(d)).count()
+    }
+
+    /// Appends every descendant of this entity to `out`, in depth-first pre-order.
+    ///
+    /// Existing contents of `out` are kept. Hot loops can keep one buffer around and
+    /// `clear()` it before each call, so its capacity is reused instead of allocating
+    /// a fresh `Vec` per traversal.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_entity_ptr::{EntityHandle, EntityPtr, HasChildren, WorldExt};
+    ///
+    /// #[derive(Component)]
+    /// struct Kids(Vec<EntityHandle>);
+    ///
+    /// impl HasChildren for Kids {
+    ///     fn children_handles(&self) -> &[EntityHandle] {
+    ///         &self.0
+    ///     }
+    /// }
+    ///
+    /// fn count_all(world: &World, roots: &[Entity], scratch: &mut Vec<EntityPtr>) -> usize {
+    ///     let mut total = 0;
+    ///     for &root in roots {
+    ///         scratch.clear();
+    ///         world.entity_ptr(root).nav_many().extend_descendants::<Kids>(scratch);
+    ///         total += scratch.len();
+    ///     }
+    ///     total
+    /// }
+    /// ```
+    pub fn extend_descendants<T: Component + HasChildren>(self, out: &mut Vec<EntityPtr>) {
+        out.extend(self.descendants::<T>());
+    }
+
+    /// Collects the raw `Entity` IDs of this entity's subtree in depth-first pre-order.
+    ///
+    /// When `include_self` is `true` this entity comes first. Unlike `EntityPtr`,
+    /// `Entity` is `Send` and carries no world reference, so the result can outlive
+    /// the read phase. This is the usual way to despawn a whole subtree: collect the
+    /// entities, let the `EntityPtr`s go out of scope, then despawn via `&mut World`.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_entity_ptr::{EntityHandle, HasChildren, WorldExt};
+    ///
+    /// #[derive(Component)]
+    /// struct Kids(Vec<EntityHandle>);
+    ///
+    /// impl HasChildren for Kids {
+    ///     fn children_handles(&self) -> &[EntityHandle] {
+    ///         &self.0
+    ///     }
+    /// }
+    ///
+    /// fn despawn_recursive(world: &mut World, root: Entity) {
+    ///     let doomed = world.entity_ptr(root).nav_many().descendant_entities::<Kids>(true);
+    ///     for entity in doomed {
+    ///         world.despawn(entity);
+    ///     }
+    /// }
+    /// ```
+    pub fn descendant_entities<T: Component + HasChildren>(
+        self,
+        include_self: bool,
+    ) -> Vec<Entity> {
+        collect_descendant_entities::<T>(self.0.world(), self.0.entity(), include_self)
+    }
+
+    /// Collects the raw `Entity` IDs of this entity's descendants in depth-first pre-order.
+    ///
+    /// The entity itself is excluded; equivalent to `descendant_entities(false)`.
+    /// Together with `descendants_entities_bfs()` this is the bridge from a read-only
+    /// traversal to mutation: `Entity` is `Send` and holds no world reference, so
+    /// the IDs stay usable after the `WorldRef` scope ends.
+    ///
+    /// # Read-then-mutate
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_entity_ptr::{EntityHandle, HasChildren, WorldExt};
+    ///
+    /// #[derive(Component)]
+    /// struct Kids(Vec<EntityHandle>);
+    ///
+    /// impl HasChildren for Kids {
+    ///     fn children_handles(&self) -> &[EntityHandle] {
+    ///         &self.0
+    ///     }
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Hidden;
+    ///
+    /// let mut world = World::new();
+    /// let leaf = world.spawn_empty().id();
+    /// let root = world.spawn(Kids(vec![EntityHandle::new(leaf)])).id();
+    ///
+    /// // Read phase: collect IDs while the world is borrowed immutably
+    /// let targets = world.entity_ptr(root).nav_many().descendants_entities_dfs::<Kids>();
+    ///
+    /// // Mutate phase: the pointers are gone, the IDs remain
+    /// for entity in targets {
+    ///     world.entity_mut(entity).insert(Hidden);
+    /// }
+    /// assert!(world.get::<Hidden>(leaf).is_some());
+    /// ```
+    pub fn descendants_entities_dfs<T: Component + HasChildren>(self) -> Vec<Entity> {
+        self.descendant_entities::<T>(false)
+    }
+
+    /// Collects the raw `Entity` IDs of this entity's descendants in breadth-first order.
+    ///
+    /// The entity itself is excluded; all children come before any grandchildren,
+    /// each level in stored order. Useful when mutations must be applied top-down
+    /// level by level. See `descendants_entities_dfs()` for the read-then-mutate
+    /// workflow. The hierarchy formed by `T` must be acyclic.
+    pub fn descendants_entities_bfs<T: Component + HasChildren>(self) -> Vec<Entity> {
+        collect_descendant_entities_bfs::<T>(self.0.world(), self.0.entity())
+    }
+
+    /// Collects `EntityHandle`s for this entity's subtree in depth-first pre-order.
+    ///
+    /// When `include_self` is `true` this entity comes first. Unlike the pointers,
+    /// handles hold no world reference, so the result can be stored in a component
+    /// or kept across frames, e.g. for persistence.
+    pub fn descendant_handles<T: Component + HasChildren>(
+        self,
+        include_self: bool,
+    ) -> Vec<EntityHandle> {
+        self.descendant_entities::<T>(include_self)
+            .into_iter()
+            .map(EntityHandle::new)
+            .collect()
+    }
+
+    /// Returns the path from this entity to the first descendant matching `pred`.
+    ///
+    /// The path starts with this entity and ends with the match. The search is
+    /// breadth-first, so the match is one of the shallowest, and this entity itself
+    /// is never tested. Returns `None` if no descendant matches. Useful for tree
+    /// pathfinding, e.g. expanding every node on the way to a selected leaf.
+    /// Entities are visited at most once, so cycles cannot hang the search.
+    pub fn find_path_to<T, P>(self, mut pred: P) -> Option<Vec<EntityPtr>>
+    where
+        T: Component + HasChildren,
+        P: FnMut(EntityPtr) -> bool,
+    {
+        let world = self.0.world();
+        let path =
+            find_path_entities::<T, _>(world, self.0.entity(), |e| pred(EntityPtr::new(e, world)))?;
+        Some(path.into_iter().map(|e| EntityPtr::new(e, world)).collect())
+    }
+
+    /// Yields a `(parent, child)` pair for every link in this entity's subtree.
+    ///
+    /// Parents are visited in depth-first pre-order starting with this entity, and
+    /// each emits its children in stored order, so the output is deterministic.
+    /// Feeds graph-drawing tools such as Graphviz directly. The hierarchy formed by
+    /// `T` must be acyclic; a cycle makes the iterator run forever.
+    pub fn child_edges<T: Component + HasChildren>(self) -> impl Iterator<Item = (Entity, Entity)> {
+        subtree_edges::<T>(self.0.world(), self.0.entity())
+    }
+}
+
+/// The difference between two parents' child lists, as returned by [`diff_children`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChildDiff {
+    /// Children of `b` that are not children of `a`, in `b`'s order.
+    pub added: Vec<Entity>,
+    /// Children of `a` that are not children of `b`, in `a`'s order.
+    pub removed: Vec<Entity>,
+    /// Children of both, in `a`'s order.
+    pub common: Vec<Entity>,
+}
+
+impl ChildDiff {
+    /// Returns `true` if both parents have the same set of children.
+    #[inline]
+    pub fn is_unchanged(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Compares the children of `a` (old) and `b` (new) via `T`.
+///
+/// Treats each child list as a set; order changes alone produce no `added` or
+/// `removed` entries. A missing component counts as no children. Useful for
+/// incremental UI updates when a node's children change.
+///
+/// # Example
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_entity_ptr::{EntityHandle, HasChildren, WorldExt, diff_children};
+///
+/// #[derive(Component)]
+/// struct Kids(Vec<EntityHandle>);
+///
+/// impl HasChildren for Kids {
+///     fn children_handles(&self) -> &[EntityHandle] {
+///         &self.0
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// let [x, y, z] = [(); 3].map(|()| world.spawn_empty().id());
+/// let old = world.spawn(Kids([x, y].map(EntityHandle::new).to_vec())).id();
+/// let new = world.spawn(Kids([y, z].map(EntityHandle::new).to_vec())).id();
+///
+/// let diff = diff_children::<Kids>(world.entity_ptr(old), world.entity_ptr(new));
+/// assert_eq!((diff.added, diff.removed, diff.common), (vec![z], vec![x], vec![y]));
+/// ```
+pub fn diff_children<T: Component + HasChildren>(a: EntityPtr, b: EntityPtr) -> ChildDiff {
+    let old = child_handles::<T>(a.world(), a.entity());
+    let new = child_handles::<T>(b.world(), b.entity());
+    let old_set: HashSet<Entity> = old.iter().map(|h| h.entity()).collect();
+    let new_set: HashSet<Entity> = new.iter().map(|h| h.entity()).collect();
+
+    let (common, removed) = old
+        .iter()
+        .map(|h| h.entity())
+        .partition(|e| new_set.contains(e));
+    let added = new
+        .iter()
+        .map(|h| h.entity())
+        .filter(|e| !old_set.contains(e))
+        .collect();
+    ChildDiff {
+        added,
+        removed,
+        common,
+    }
+}
+
+/// Returns the deepest entity that is an ancestor-or-self of every entity in `ptrs`.
+///
+/// Parents are found via `P`. An entity counts as its own ancestor, so if one
+/// selected entity contains all the others it is the result. Returns `None` for an
+/// empty slice or when the entities share no root. Cycle-safe, like
+/// `EntityPtrNav::ancestors()`. Useful for multi-selection in editors.
+pub fn common_root<P: Component + HasParent>(ptrs: &[EntityPtr]) -> Option<EntityPtr> {
+    let (first, rest) = ptrs.split_first()?;
+    let world = first.world();
+    let lineage = |e: Entity| -> HashSet<Entity> {
+        std::iter::once(e)
+            .chain(Ancestors::<P>::new(world, e))
+            .collect()
+    };
+    let others: Vec<HashSet<Entity>> = rest.iter().map(|p| lineage(p.entity())).collect();
+
+    std::iter::once(first.entity())
+        .chain(Ancestors::<P>::new(world, first.entity()))
+        .find(|e| others.iter().all(|set| set.contains(e)))
+        .map(|e| EntityPtr::new(e, world))
+}
+
+/// Returns the children of `a` followed by those of `b`, without duplicates.
+///
+/// Children are compared by `Entity`; `a`'s children keep their order and come
+/// first, then any of `b`'s not already present. A missing component counts as no
+/// children. Useful for overlay/merge semantics in editors. Both pointers must read
+/// from the same world (checked in debug builds).
+pub fn union_children<T: Component + HasChildren>(a: EntityPtr, b: EntityPtr) -> Vec<EntityPtr> {
+    debug_assert!(
+        a.merge_world(b).is_some(),
+        "union_children called with pointers from different worlds"
+    );
+    let world = a.world();
+    let mut seen = HashSet::new();
+    child_handles::<T>(world, a.entity())
+        .iter()
+        .chain(child_handles::<T>(world, b.entity()))
+        .map(|h| h.entity())
+        .filter(|&e| seen.insert(e))
+        .map(|e| EntityPtr::new(e, world))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ptr::WorldRef;
+
+    #[derive(Component)]
+    struct Name(&'static str);
+
+    #[derive(Component)]
+    struct Health(i32);
+
+    #[derive(Component)]
+    struct Damage(i32);
+
+    #[derive(Component)]
+    struct TeamRef(EntityHandle);
+
+    #[derive(Component)]
+    struct ParentRefs(Vec<EntityHandle>);
+
+    impl HasParents for ParentRefs {
+        fn parent_handles(&self) -> &[EntityHandle] {
+            &self.0
+        }
+    }
+
+    #[derive(Component)]
+    struct ParentRef(Option<EntityHandle>);
+
+    impl HasParent for ParentRef {
+        fn parent_handle(&self) -> Option<EntityHandle> {
+            self.0
+        }
+    }
+
+    #[derive(Component)]
+    struct ChildRefs(Vec<EntityHandle>);
+
+    impl HasChildren for ChildRefs {
+        fn children_handles(&self) -> &[EntityHandle] {
+            &self.0
+        }
+    }
+
+    #[test]
+    fn bound_entity_nav_parent() {
+        let mut world = World::new();
+        let parent = world.spawn(Name("parent")).id();
+        let child = world
+            .spawn((Name("child"), ParentRef(Some(EntityHandle::new(parent)))))
+            .id();
+
+        let bound = EntityHandle::new(child).bind(&world);
+        let parent_bound = bound.nav().parent::<ParentRef>().unwrap();
+
+        assert_eq!(parent_bound.get::<Name>().unwrap().0, "parent");
+    }
+
+    #[test]
+    fn bound_entity_nav_parent_none() {
+        let mut world = World::new();
+        let orphan = world.spawn((Name("orphan"), ParentRef(None))).id();
+
+        let bound = EntityHandle::new(orphan).bind(&world);
+        assert!(bound.nav().parent::<ParentRef>().is_none());
+    }
+
+    #[test]
+    fn bound_entity_nav_children() {
+        let mut world = World::new();
+        let child1 = world.spawn(Name("child1")).id();
+        let child2 = world.spawn(Name("child2")).id();
+        let parent = world
+            .spawn((
+                Name("parent"),
+                ChildRefs(vec![EntityHandle::new(child1), EntityHandle::new(child2)]),
+            ))
+            .id();
+
+        let bound = EntityHandle::new(parent).bind(&world);
+        let children: Vec<_> = bound.nav().children::<ChildRefs>().collect();
+
+        assert_eq!(children.len(), 2);
+        let names: Vec<_> = children
+            .iter()
+            .map(|c| c.get::<Name>().unwrap().0)
+            .collect();
+        assert!(names.contains(&"child1"));
+        assert!(names.contains(&"child2"));
+    }
+
+    #[test]
+    fn entityptr_nav_parent() {
+        let mut world = World::new();
+        let parent = world.spawn(Name("parent")).id();
+        let child = world
+            .spawn((Name("child"), ParentRef(Some(EntityHandle::new(parent)))))
+            .id();
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let ptr = world_ref.entity(child);
+        let parent_ptr = ptr.nav().parent::<ParentRef>().unwrap();
+
+        assert_eq!(parent_ptr.get::<Name>().unwrap().0, "parent");
+    }
+
+    #[test]
+    fn entityptr_nav_many_children() {
+        let mut world = World::new();
+        let child1 = world.spawn(Name("child1")).id();
+        let child2 = world.spawn(Name("child2")).id();
+        let parent = world
+            .spawn((
+                Name("parent"),
+                ChildRefs(vec![EntityHandle::new(child1), EntityHandle::new(child2)]),
+            ))
+            .id();
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let ptr = world_ref.entity(parent);
+        let children: Vec<_> = ptr.nav_many().children::<ChildRefs>().collect();
+
+        assert_eq!(children.len(), 2);
+        let names: Vec<_> = children
+            .iter()
+            .map(|c| c.get::<Name>().unwrap().0)
+            .collect();
+        assert!(names.contains(&"child1"));
+        assert!(names.contains(&"child2"));
+    }
+
+    // =========================================================================
+    // Empty Collection Edge Cases
+    // =========================================================================
+
+    /// Test HasChildren with empty Vec returns empty result.
+    #[test]
+    fn children_empty_vec() {
+        let mut world = World::new();
+        let parent = world.spawn((Name("parent"), ChildRefs(vec![]))).id();
+
+        // Test with BoundEntity
+        let bound = EntityHandle::new(parent).bind(&world);
+        assert_eq!(bound.nav().children::<ChildRefs>().count(), 0);
+
+        // Test with EntityPtr
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let ptr = world_ref.entity(parent);
+        assert_eq!(ptr.nav_many().children::<ChildRefs>().count(), 0);
+    }
+
+    /// Test nav_many().children() returns empty iterator when component is missing.
+    #[test]
+    fn nav_many_no_children_component() {
+        let mut world = World::new();
+        let entity = world.spawn(Name("no_children")).id();
+
+        // Test with BoundEntity - no ChildRefs component
+        let bound = EntityHandle::new(entity).bind(&world);
+        assert_eq!(bound.nav().children::<ChildRefs>().count(), 0);
+
+        // Test with EntityPtr - no ChildRefs component
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let ptr = world_ref.entity(entity);
+        assert_eq!(ptr.nav_many().children::<ChildRefs>().count(), 0);
+    }
+
+    // =========================================================================
+    // Subtree Traversal
+    // =========================================================================
+
+    /// Builds:
+    ///
+    /// ```text
+    ///       root
+    ///      /    \
+    ///     a      b
+    ///     |
+    ///     c
+    /// ```
+    fn spawn_small_tree(world: &mut World) -> [Entity; 4] {
+        let c = world.spawn(Name("c")).id();
+        let a = world
+            .spawn((Name("a"), ChildRefs(vec![EntityHandle::new(c)])))
+            .id();
+        let b = world.spawn(Name("b")).id();
+        let root = world
+            .spawn((
+                Name("root"),
+                ChildRefs(vec![EntityHandle::new(a), EntityHandle::new(b)]),
+            ))
+            .id();
+        [root, a, b, c]
+    }
+
+    /// Builds a balanced binary tree `depth` levels below the returned root.
+    fn spawn_binary_tree(world: &mut World, depth: usize) -> Entity {
+        let children = if depth == 0 {
+            Vec::new()
+        } else {
+            (0..2)
+                .map(|_| EntityHandle::new(spawn_binary_tree(world, depth - 1)))
+                .collect()
+        };
+        world.spawn(ChildRefs(children)).id()
+    }
+
+    #[test]
+    fn children_slice_borrows_handles() {
+        let mut world = World::new();
+        let [root, a, b, c] = spawn_small_tree(&mut world);
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let slice = world_ref
+            .entity(root)
+            .children_slice::<ChildRefs>()
+            .unwrap();
+        assert_eq!(slice, [a, b].map(EntityHandle::new));
+        assert!(std::ptr::eq(
+            slice,
+            world.get::<ChildRefs>(root).unwrap().0.as_slice()
+        ));
+        assert!(world_ref.entity(b).children_slice::<ChildRefs>().is_none());
+
+        let bound = EntityHandle::new(a).bind(&world);
+        assert_eq!(
+            bound.children_slice::<ChildRefs>(),
+            Some(&[EntityHandle::new(c)][..])
+        );
+    }
+
+    #[test]
+    fn children_with_handles_pairs() {
+        let mut world = World::new();
+        let [root, a, b, _c] = spawn_small_tree(&mut world);
+        world.entity_mut(b).insert(Health(0));
+
+        {
+            // SAFETY: world outlives usage and is not mutated until this block ends
+            let world_ref = unsafe { WorldRef::new(&world) };
+            let pairs: Vec<_> = world_ref
+                .entity(root)
+                .nav_many()
+                .children_with_handles::<ChildRefs>()
+                .collect();
+            assert_eq!(pairs.len(), 2);
+            assert_eq!(pairs[0].0, EntityHandle::new(a));
+            assert_eq!(pairs[1].1.entity(), b);
+        }
+
+        // Write back a filtered list using the original handles
+        let kept: Vec<EntityHandle> = EntityHandle::new(root)
+            .bind(&world)
+            .nav()
+            .children_with_handles::<ChildRefs>()
+            .filter(|(_, child)| !child.has::<Health>())
+            .map(|(handle, _)| handle)
+            .collect();
+        world.entity_mut(root).insert(ChildRefs(kept));
+        assert_eq!(
+            world.get::<ChildRefs>(root).unwrap().0,
+            vec![EntityHandle::new(a)]
+        );
+    }
+
+    #[test]
+    fn parents_yields_every_dag_parent() {
+        let mut world = World::new();
+        let left = world.spawn(Name("left")).id();
+        let right = world.spawn(Name("right")).id();
+        let shared = world
+            .spawn(ParentRefs(vec![
+                EntityHandle::new(left),
+                EntityHandle::new(right),
+            ]))
+            .id();
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let parents: Vec<Entity> = world_ref
+            .entity(shared)
+            .nav_many()
+            .parents::<ParentRefs>()
+            .map(|p| p.entity())
+            .collect();
+        assert_eq!(parents, vec![left, right]);
+        assert_eq!(
+            world_ref
+                .entity(left)
+                .nav_many()
+                .parents::<ParentRefs>()
+                .count(),
+            0
+        );
+
+        let names: Vec<&str> = EntityHandle::new(shared)
+            .bind(&world)
+            .nav()
+            .parents::<ParentRefs>()
+            .map(|p| p.get::<Name>().unwrap().0)
+            .collect();
+        assert_eq!(names, vec!["left", "right"]);
+    }
+
+    #[test]
+    fn follow_then_children_lists_members() {
+        let mut world = World::new();
+        let [team, a, b, _c] = spawn_small_tree(&mut world);
+        let player = world.spawn(TeamRef(EntityHandle::new(team))).id();
+        let loner = world.spawn(Name("loner")).id();
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let members: Vec<Entity> = world_ref
+            .entity(player)
+            .nav_many()
+            .follow_then_children::<TeamRef, ChildRefs, _>(|t| t.0)
+            .map(|p| p.entity())
+            .collect();
+        assert_eq!(members, vec![a, b]);
+        assert_eq!(
+            world_ref
+                .entity(loner)
+                .nav_many()
+                .follow_then_children::<TeamRef, ChildRefs, _>(|t| t.0)
+                .count(),
+            0
+        );
+
+        let bound: Vec<Entity> = EntityHandle::new(player)
+            .bind(&world)
+            .nav()
+            .follow_then_children::<TeamRef, ChildRefs, _>(|t| t.0)
+            .map(|b| b.entity())
+            .collect();
+        assert_eq!(bound, vec![a, b]);
+    }
+
+    #[test]
+    fn is_root_and_is_leaf() {
+        let mut world = World::new();
+        let [root, a, b, c] = spawn_small_tree(&mut world);
+        world
+            .entity_mut(a)
+            .insert(ParentRef(Some(EntityHandle::new(root))));
+        world
+            .entity_mut(c)
+            .insert(ParentRef(Some(EntityHandle::new(a))));
+        world.entity_mut(b).insert(ParentRef(None));
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let ptr = |e| world_ref.entity(e);
+
+        // Root: no ParentRef at all, has children
+        assert!(ptr(root).nav().is_root::<ParentRef>());
+        assert!(!ptr(root).nav_many().is_leaf::<ChildRefs>());
+        // Middle node
+        assert!(!ptr(a).nav().is_root::<ParentRef>());
+        assert!(!ptr(a).nav_many().is_leaf::<ChildRefs>());
+        // Leaves: no ChildRefs component
+        assert!(!ptr(c).nav().is_root::<ParentRef>());
+        assert!(ptr(c).nav_many().is_leaf::<ChildRefs>());
+        // A `None` parent handle also counts as a root
+        assert!(ptr(b).nav().is_root::<ParentRef>());
+        assert!(ptr(b).nav_many().is_leaf::<ChildRefs>());
+
+        let bound = |e| EntityHandle::new(e).bind(&world);
+        assert!(bound(root).nav().is_root::<ParentRef>());
+        assert!(!bound(a).nav().is_root::<ParentRef>());
+        assert!(!bound(a).nav().is_leaf::<ChildRefs>());
+        assert!(bound(c).nav().is_leaf::<ChildRefs>());
+    }
+
+    #[test]
+    fn sort_children_by_health() {
+        let mut world = World::new();
+        let [d, e, f, g] = [(); 4].map(|_| world.spawn_empty().id());
+        world.entity_mut(d).insert(Health(30));
+        world.entity_mut(f).insert(Health(10));
+        world.entity_mut(g).insert(Health(20));
+        let root = world
+            .spawn(ChildRefs([d, e, f, g].map(EntityHandle::new).to_vec()))
+            .id();
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let mut children: Vec<EntityPtr> = world_ref
+            .entity(root)
+            .nav_many()
+            .children::<ChildRefs>()
+            .collect();
+        crate::sort_by_component::<Health, _, _>(&mut children, |h| h.0);
+
+        // Ascending by health, the child without one last
+        let order: Vec<Entity> = children.iter().map(|c| c.entity()).collect();
+        assert_eq!(order, vec![f, g, d, e]);
+    }
+
+    #[test]
+    fn collect_children_component_skips_missing() {
+        let mut world = World::new();
+        let [root, a, b, c] = spawn_small_tree(&mut world);
+        world.entity_mut(a).insert(Health(10));
+        world.entity_mut(c).insert(Health(99));
+
+        {
+            // SAFETY: world outlives usage and is not mutated until this block ends
+            let world_ref = unsafe { WorldRef::new(&world) };
+            let healths = world_ref
+                .entity(root)
+                .nav_many()
+                .collect_children_component::<ChildRefs, Health>();
+            assert_eq!(healths.iter().map(|h| h.0).collect::<Vec<_>>(), vec![10]);
+        }
+
+        world.entity_mut(b).insert(Health(20));
+        let bound = EntityHandle::new(root).bind(&world);
+        let healths = bound
+            .nav()
+            .collect_children_component::<ChildRefs, Health>();
+        assert_eq!(
+            healths.iter().map(|h| h.0).collect::<Vec<_>>(),
+            vec![10, 20]
+        );
+        assert!(
+            EntityHandle::new(b)
+                .bind(&world)
+                .nav()
+                .collect_children_component::<ChildRefs, Health>()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn descendants_pre_order() {
+        let mut world = World::new();
+        let [root, a, b, c] = spawn_small_tree(&mut world);
+
+        let bound: Vec<_> = EntityHandle::new(root)
+            .bind(&world)
+            .nav()
+            .descendants::<ChildRefs>()
+            .map(|d| d.entity())
+            .collect();
+        assert_eq!(bound, vec![a, c, b]);
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let ptr: Vec<_> = world_ref
+            .entity(root)
+            .nav_many()
+            .descendants::<ChildRefs>()
+            .map(|d| d.entity())
+            .collect();
+        assert_eq!(ptr, vec![a, c, b]);
+    }
+
+    #[test]
+    fn descendant_entities_then_despawn() {
+        let mut world = World::new();
+        let [root, a, b, c] = spawn_small_tree(&mut world);
+        let bystander = world.spawn(Name("bystander")).id();
+
+        let subtree = {
+            // SAFETY: world outlives usage and is not mutated until this block ends
+            let world_ref = unsafe { WorldRef::new(&world) };
+            let nav = world_ref.entity(a).nav_many();
+            assert_eq!(nav.descendant_entities::<ChildRefs>(false), vec![c]);
+
+            world_ref
+                .entity(root)
+                .nav_many()
+                .descendant_entities::<ChildRefs>(true)
+        };
+        assert_eq!(subtree, vec![root, a, c, b]);
+
+        // Read phase over: the collected entities drive the mutation.
+        for &entity in &subtree {
+            world.despawn(entity);
+        }
+        assert!(subtree.iter().all(|&e| world.get_entity(e).is_err()));
+        assert!(world.get_entity(bystander).is_ok());
+    }
+
+    #[test]
+    fn count_descendants_where_health() {
+        let mut world = World::new();
+        let [root, a, _b, c] = spawn_small_tree(&mut world);
+        world.entity_mut(a).insert(Health(3));
+        world.entity_mut(c).insert(Health(0));
+        world.entity_mut(root).insert(Health(9));
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let nav = world_ref.entity(root).nav_many();
+        assert_eq!(
+            nav.count_descendants_where::<ChildRefs, _>(|d| d.has::<Health>()),
+            2
+        );
+        assert_eq!(
+            nav.count_descendants_where::<ChildRefs, _>(|d| d
+                .get::<Health>()
+                .is_some_and(|h| h.0 > 0)),
+            1
+        );
+
+        let bound = EntityHandle::new(root).bind(&world).nav();
+        assert_eq!(
+            bound.count_descendants_where::<ChildRefs, _>(|d| d.has::<Health>()),
+            2
+        );
+        assert_eq!(bound.count_descendants_where::<ChildRefs, _>(|_| true), 3);
+    }
+
+    #[test]
+    fn group_children_by_damage() {
+        let mut world = World::new();
+        let sword = world.spawn((Name("sword"), Damage(5))).id();
+        let shield = world.spawn(Name("shield")).id();
+        let axe = world.spawn((Name("axe"), Damage(8))).id();
+        let inventory = world
+            .spawn(ChildRefs(vec![
+                EntityHandle::new(sword),
+                EntityHandle::new(shield),
+                EntityHandle::new(axe),
+            ]))
+            .id();
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let groups = world_ref
+            .entity(inventory)
+            .nav_many()
+            .group_children_by::<ChildRefs, _, _>(|c| c.has::<Damage>());
+        let weapons: Vec<_> = groups[&true].iter().map(|c| c.entity()).collect();
+        assert_eq!(groups.len(), 2);
+        assert_eq!(weapons, vec![sword, axe]);
+        assert_eq!(groups[&false][0].entity(), shield);
+
+        let bound = EntityHandle::new(inventory).bind(&world).nav();
+        let by_name = bound.group_children_by::<ChildRefs, _, _>(|c| c.get::<Name>().unwrap().0);
+        assert_eq!(by_name.len(), 3);
+        assert_eq!(by_name["axe"][0].entity(), axe);
+    }
+
+    #[test]
+    fn min_max_child_by_health() {
+        let mut world = World::new();
+        let weak = world.spawn(Health(5)).id();
+        let unarmored = world.spawn(Name("no health")).id();
+        let strong = world.spawn(Health(80)).id();
+        let middling = world.spawn(Health(40)).id();
+        let squad = world
+            .spawn(ChildRefs(vec![
+                EntityHandle::new(middling),
+                EntityHandle::new(weak),
+                EntityHandle::new(unarmored),
+                EntityHandle::new(strong),
+            ]))
+            .id();
+        let empty = world
+            .spawn(ChildRefs(vec![EntityHandle::new(unarmored)]))
+            .id();
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let nav = world_ref.entity(squad).nav_many();
+        let min = nav.min_child_by_key::<ChildRefs, Health, _, _>(|h| h.0);
+        let max = nav.max_child_by_key::<ChildRefs, Health, _, _>(|h| h.0);
+        assert_eq!(min.map(|c| c.entity()), Some(weak));
+        assert_eq!(max.map(|c| c.entity()), Some(strong));
+
+        let bound = EntityHandle::new(squad).bind(&world).nav();
+        let min = bound.min_child_by_key::<ChildRefs, Health, _, _>(|h| h.0);
+        assert_eq!(min.map(|c| c.entity()), Some(weak));
+
+        // No child has Health
+        let nav = world_ref.entity(empty).nav_many();
+        assert!(
+            nav.min_child_by_key::<ChildRefs, Health, _, _>(|h| h.0)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn siblings_and_only_child() {
+        let mut world = World::new();
+        let [root, a, b, c] = spawn_small_tree(&mut world);
+        for (child, parent) in [(a, root), (b, root), (c, a)] {
+            world
+                .entity_mut(child)
+                .insert(ParentRef(Some(EntityHandle::new(parent))));
+        }
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let a_nav = world_ref.entity(a).nav();
+        let siblings: Vec<_> = a_nav
+            .siblings::<ParentRef, ChildRefs>()
+            .map(|s| s.entity())
+            .collect();
+        assert_eq!(siblings, vec![b]);
+        assert_eq!(a_nav.siblings_count::<ParentRef, ChildRefs>(), 1);
+        assert!(!a_nav.is_only_child::<ParentRef, ChildRefs>());
+
+        let c_nav = world_ref.entity(c).nav();
+        assert_eq!(c_nav.siblings_count::<ParentRef, ChildRefs>(), 0);
+        assert!(c_nav.is_only_child::<ParentRef, ChildRefs>());
+
+        // The root has no parent: no siblings, and not an only child
+        let root_nav = world_ref.entity(root).nav();
+        assert_eq!(root_nav.siblings_count::<ParentRef, ChildRefs>(), 0);
+        assert!(!root_nav.is_only_child::<ParentRef, ChildRefs>());
+
+        let b_bound = EntityHandle::new(b).bind(&world).nav();
+        assert_eq!(b_bound.siblings_count::<ParentRef, ChildRefs>(), 1);
+        assert_eq!(
+            b_bound
+                .siblings::<ParentRef, ChildRefs>()
+                .next()
+                .map(|s| s.entity()),
+            Some(a)
+        );
+        let c_bound = EntityHandle::new(c).bind(&world).nav();
+        assert!(c_bound.is_only_child::<ParentRef, ChildRefs>());
+    }
+
+    #[test]
+    fn extend_descendants_reuses_buffer() {
+        let mut world = World::new();
+        let [root, a, b, c] = spawn_small_tree(&mut world);
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let mut buffer = vec![world_ref.entity(root)];
+        world_ref
+            .entity(root)
+            .nav_many()
+            .extend_descendants::<ChildRefs>(&mut buffer);
+        let entities: Vec<_> = buffer.iter().map(|p| p.entity()).collect();
+        assert_eq!(entities, vec![root, a, c, b]);
+
+        let capacity = buffer.capacity();
+        buffer.clear();
+        world_ref
+            .entity(a)
+            .nav_many()
+            .extend_descendants::<ChildRefs>(&mut buffer);
+        assert_eq!(buffer.len(), 1);
+        assert_eq!(buffer.capacity(), capacity);
+
+        let mut bound_buffer = Vec::new();
+        EntityHandle::new(a)
+            .bind(&world)
+            .nav()
+            .extend_descendants::<ChildRefs>(&mut bound_buffer);
+        assert_eq!(bound_buffer[0].entity(), c);
     }
 
     #[test]
-    fn entityptr_nav_parent() {
+    fn fold_children_sums_direct_health() {
         let mut world = World::new();
-        let parent = world.spawn(Name("parent")).id();
-        let child = world
-            .spawn((Name("child"), ParentRef(Some(EntityHandle::new(parent)))))
-            .id();
+        let [root, a, b, c] = spawn_small_tree(&mut world);
+        world.entity_mut(a).insert(Health(3));
+        world.entity_mut(b).insert(Health(4));
+        // Grandchild is not part of a one-level fold
+        world.entity_mut(c).insert(Health(100));
+
+        let sum = |acc: i32, h: Option<&Health>| acc + h.map_or(0, |h| h.0);
 
         // SAFETY: world outlives usage
         let world_ref = unsafe { WorldRef::new(&world) };
-        let ptr = world_ref.entity(child);
-        let parent_ptr = ptr.nav().parent::<ParentRef>().unwrap();
+        let total = world_ref
+            .entity(root)
+            .nav_many()
+            .fold_children::<ChildRefs, _, _>(0, |acc, child| sum(acc, child.get::<Health>()));
+        assert_eq!(total, 7);
 
-        assert_eq!(parent_ptr.get::<Name>().unwrap().0, "parent");
+        // Missing component returns init
+        let leaf = world_ref
+            .entity(b)
+            .nav_many()
+            .fold_children::<ChildRefs, _, _>(-1, |acc, _| acc + 1);
+        assert_eq!(leaf, -1);
+
+        let bound = EntityHandle::new(root)
+            .bind(&world)
+            .nav()
+            .fold_children::<ChildRefs, _, _>(0, |acc, child| sum(acc, child.get::<Health>()));
+        assert_eq!(bound, 7);
     }
 
     #[test]
-    fn entityptr_nav_many_children() {
+    fn reduce_children_component_sums_damage() {
         let mut world = World::new();
-        let child1 = world.spawn(Name("child1")).id();
-        let child2 = world.spawn(Name("child2")).id();
-        let parent = world
-            .spawn((
-                Name("parent"),
-                ChildRefs(vec![EntityHandle::new(child1), EntityHandle::new(child2)]),
+        let sword = world.spawn(Damage(5)).id();
+        let shield = world.spawn(Name("shield")).id();
+        let axe = world.spawn(Damage(8)).id();
+        let player = world
+            .spawn(ChildRefs(
+                [sword, shield, axe].map(EntityHandle::new).to_vec(),
             ))
             .id();
 
         // SAFETY: world outlives usage
         let world_ref = unsafe { WorldRef::new(&world) };
-        let ptr = world_ref.entity(parent);
-        let children: Vec<_> = ptr.nav_many().children::<ChildRefs>().collect();
+        let total = world_ref
+            .entity(player)
+            .nav_many()
+            .reduce_children_component::<ChildRefs, Damage, _, _>(0, |acc, d| acc + d.0);
+        assert_eq!(total, 13);
 
-        assert_eq!(children.len(), 2);
-        let names: Vec<_> = children
-            .iter()
-            .map(|c| c.get::<Name>().unwrap().0)
+        // No children, or no child with the component, returns init
+        let none = world_ref
+            .entity(sword)
+            .nav_many()
+            .reduce_children_component::<ChildRefs, Damage, _, _>(0, |acc, d| acc + d.0);
+        assert_eq!(none, 0);
+
+        let strongest = EntityHandle::new(player)
+            .bind(&world)
+            .nav()
+            .reduce_children_component::<ChildRefs, Damage, _, _>(i32::MIN, |acc, d| acc.max(d.0));
+        assert_eq!(strongest, 8);
+    }
+
+    #[test]
+    fn partition_children_by_health() {
+        let mut world = World::new();
+        let [root, a, b, _c] = spawn_small_tree(&mut world);
+        let d = world.spawn(Health(2)).id();
+        world.entity_mut(b).insert(Health(1));
+        world
+            .entity_mut(root)
+            .insert(ChildRefs([a, b, d].map(EntityHandle::new).to_vec()));
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let entities = |ptrs: Vec<EntityPtr>| ptrs.iter().map(|p| p.entity()).collect::<Vec<_>>();
+        let (healthy, rest) = world_ref
+            .entity(root)
+            .nav_many()
+            .partition_children::<ChildRefs, _>(|c| c.has::<Health>());
+        assert_eq!(entities(healthy), vec![b, d]);
+        assert_eq!(entities(rest), vec![a]);
+
+        let (yes, no) = EntityHandle::new(d)
+            .bind(&world)
+            .nav()
+            .partition_children::<ChildRefs, _>(|_| true);
+        assert!(yes.is_empty() && no.is_empty());
+    }
+
+    #[test]
+    fn index_of_child_positions() {
+        let mut world = World::new();
+        let [root, a, b, c] = spawn_small_tree(&mut world);
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let nav = world_ref.entity(root).nav_many();
+        assert_eq!(nav.index_of_child::<ChildRefs>(a), Some(0));
+        assert_eq!(nav.index_of_child::<ChildRefs>(b), Some(1));
+        // Grandchildren are not immediate children
+        assert_eq!(nav.index_of_child::<ChildRefs>(c), None);
+        // Missing component
+        assert_eq!(
+            world_ref
+                .entity(b)
+                .nav_many()
+                .index_of_child::<ChildRefs>(a),
+            None
+        );
+
+        let bound = EntityHandle::new(a).bind(&world).nav();
+        assert_eq!(bound.index_of_child::<ChildRefs>(c), Some(0));
+        assert_eq!(bound.index_of_child::<ChildRefs>(root), None);
+    }
+
+    #[test]
+    fn descendants_within_depth_cap() {
+        let mut world = World::new();
+        let [root, a, b, c] = spawn_small_tree(&mut world);
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let nav = world_ref.entity(root).nav_many();
+        let within = |depth| -> Vec<Entity> {
+            nav.descendants_within::<ChildRefs>(depth)
+                .map(|d| d.entity())
+                .collect()
+        };
+        assert!(within(0).is_empty());
+        assert_eq!(within(1), vec![a, b]);
+        assert_eq!(within(2), vec![a, c, b]);
+        assert_eq!(within(usize::MAX), vec![a, c, b]);
+
+        let bound: Vec<_> = EntityHandle::new(root)
+            .bind(&world)
+            .nav()
+            .descendants_within::<ChildRefs>(1)
+            .map(|d| d.entity())
             .collect();
-        assert!(names.contains(&"child1"));
-        assert!(names.contains(&"child2"));
+        assert_eq!(bound, vec![a, b]);
     }
 
-    // =========================================================================
-    // Empty Collection Edge Cases
-    // =========================================================================
+    #[test]
+    fn descendants_within_counts_per_level() {
+        // Binary tree of depth 4: 2 + 4 + 8 + 16 descendants
+        let mut world = World::new();
+        let root = spawn_binary_tree(&mut world, 4);
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let nav = world_ref.entity(root).nav_many();
+        for (depth, expected) in [(0, 0), (1, 2), (2, 6), (3, 14), (4, 30), (9, 30)] {
+            assert_eq!(nav.descendants_within::<ChildRefs>(depth).count(), expected);
+        }
+    }
 
-    /// Test HasChildren with empty Vec returns empty result.
     #[test]
-    fn children_empty_vec() {
+    fn subtree_includes_root_once() {
         let mut world = World::new();
-        let parent = world.spawn((Name("parent"), ChildRefs(vec![]))).id();
+        let [root, a, b, c] = spawn_small_tree(&mut world);
 
-        // Test with BoundEntity
-        let bound = EntityHandle::new(parent).bind(&world);
-        assert_eq!(bound.nav().children::<ChildRefs>().count(), 0);
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let ptr: Vec<_> = world_ref
+            .entity(root)
+            .nav_many()
+            .subtree::<ChildRefs>()
+            .map(|e| e.entity())
+            .collect();
+        assert_eq!(ptr, vec![root, a, c, b]);
+
+        // A leaf's subtree is just itself
+        let leaf: Vec<_> = world_ref
+            .entity(b)
+            .nav_many()
+            .subtree::<ChildRefs>()
+            .map(|e| e.entity())
+            .collect();
+        assert_eq!(leaf, vec![b]);
+
+        let bound: Vec<_> = EntityHandle::new(a)
+            .bind(&world)
+            .nav()
+            .subtree::<ChildRefs>()
+            .map(|e| e.entity())
+            .collect();
+        assert_eq!(bound, vec![a, c]);
+    }
+
+    #[test]
+    fn verify_bidirectional_links() {
+        let mut world = World::new();
+        let [root, a, b, c] = spawn_small_tree(&mut world);
+        for (child, parent) in [(a, root), (b, root), (c, a)] {
+            world
+                .entity_mut(child)
+                .insert(ParentRef(Some(EntityHandle::new(parent))));
+        }
+        // Desynced: claims `b` as parent, but `b` has no ChildRefs
+        let stray = world.spawn(ParentRef(Some(EntityHandle::new(b)))).id();
+        // Desynced: claims `root` as parent, but is not listed by it
+        let forgotten = world.spawn(ParentRef(Some(EntityHandle::new(root)))).id();
 
-        // Test with EntityPtr
         // SAFETY: world outlives usage
         let world_ref = unsafe { WorldRef::new(&world) };
-        let ptr = world_ref.entity(parent);
-        assert_eq!(ptr.nav_many().children::<ChildRefs>().count(), 0);
+        let check = |e| {
+            world_ref
+                .entity(e)
+                .nav()
+                .verify_bidirectional::<ParentRef, ChildRefs>()
+        };
+        assert!(check(a));
+        assert!(check(c));
+        assert!(!check(root));
+        assert!(!check(stray));
+        assert!(!check(forgotten));
+
+        let bound = EntityHandle::new(b).bind(&world).nav();
+        assert!(bound.verify_bidirectional::<ParentRef, ChildRefs>());
+        let bound = EntityHandle::new(forgotten).bind(&world).nav();
+        assert!(!bound.verify_bidirectional::<ParentRef, ChildRefs>());
     }
 
-    /// Test nav_many().children() returns empty iterator when component is missing.
     #[test]
-    fn nav_many_no_children_component() {
+    fn ancestors_and_ancestor_until() {
         let mut world = World::new();
-        let entity = world.spawn(Name("no_children")).id();
+        let [root, a, _b, c] = spawn_small_tree(&mut world);
+        world
+            .entity_mut(a)
+            .insert((Health(10), ParentRef(Some(EntityHandle::new(root)))));
+        world
+            .entity_mut(c)
+            .insert((Health(1), ParentRef(Some(EntityHandle::new(a)))));
 
-        // Test with BoundEntity - no ChildRefs component
-        let bound = EntityHandle::new(entity).bind(&world);
-        assert_eq!(bound.nav().children::<ChildRefs>().count(), 0);
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let nav = world_ref.entity(c).nav();
+        let chain: Vec<_> = nav.ancestors::<ParentRef>().map(|p| p.entity()).collect();
+        assert_eq!(chain, vec![a, root]);
+
+        // Self is excluded even though it has Health
+        let found = nav.ancestor_until::<ParentRef, _>(|p| p.has::<Health>());
+        assert_eq!(found.map(|p| p.entity()), Some(a));
+        let found = nav.ancestor_until::<ParentRef, _>(|p| p.get::<Name>().unwrap().0 == "root");
+        assert_eq!(found.map(|p| p.entity()), Some(root));
+        assert!(nav.ancestor_until::<ParentRef, _>(|_| false).is_none());
+
+        let bound = EntityHandle::new(c).bind(&world).nav();
+        let found = bound.ancestor_until::<ParentRef, _>(|p| !p.has::<Health>());
+        assert_eq!(found.map(|p| p.entity()), Some(root));
+    }
+
+    #[test]
+    fn ancestors_enumerated_counts_hops() {
+        // Linear chain: chain[0] is the root, chain[4] the deepest link
+        let mut world = World::new();
+        let mut chain = vec![world.spawn(ParentRef(None)).id()];
+        for _ in 0..4 {
+            let parent = EntityHandle::new(*chain.last().unwrap());
+            chain.push(world.spawn(ParentRef(Some(parent))).id());
+        }
 
-        // Test with EntityPtr - no ChildRefs component
         // SAFETY: world outlives usage
         let world_ref = unsafe { WorldRef::new(&world) };
-        let ptr = world_ref.entity(entity);
-        assert_eq!(ptr.nav_many().children::<ChildRefs>().count(), 0);
+        let hops: Vec<_> = world_ref
+            .entity(chain[4])
+            .nav()
+            .ancestors_enumerated::<ParentRef>()
+            .map(|(n, p)| (n, p.entity()))
+            .collect();
+        assert_eq!(
+            hops,
+            vec![(1, chain[3]), (2, chain[2]), (3, chain[1]), (4, chain[0])]
+        );
+        assert_eq!(
+            world_ref
+                .entity(chain[0])
+                .nav()
+                .ancestors_enumerated::<ParentRef>()
+                .count(),
+            0
+        );
+
+        let capped: Vec<_> = EntityHandle::new(chain[4])
+            .bind(&world)
+            .nav()
+            .ancestors_enumerated::<ParentRef>()
+            .take_while(|(n, _)| *n <= 2)
+            .map(|(n, b)| (n, b.entity()))
+            .collect();
+        assert_eq!(capped, vec![(1, chain[3]), (2, chain[2])]);
+    }
+
+    #[test]
+    fn ancestor_at_known_depth() {
+        // Linear chain: chain[0] is the root, chain[3] the deepest link
+        let mut world = World::new();
+        let mut chain = vec![world.spawn(ParentRef(None)).id()];
+        for _ in 0..3 {
+            let parent = EntityHandle::new(*chain.last().unwrap());
+            chain.push(world.spawn(ParentRef(Some(parent))).id());
+        }
+        // Two-node cycle: x -> y -> x
+        let x = world.spawn_empty().id();
+        let y = world.spawn(ParentRef(Some(EntityHandle::new(x)))).id();
+        world
+            .entity_mut(x)
+            .insert(ParentRef(Some(EntityHandle::new(y))));
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let nav = world_ref.entity(chain[3]).nav();
+        for n in 0..=3 {
+            let found = nav.ancestor_at::<ParentRef>(n).map(|p| p.entity());
+            assert_eq!(found, Some(chain[3 - n]));
+        }
+        assert!(nav.ancestor_at::<ParentRef>(4).is_none());
+
+        let bound = EntityHandle::new(chain[2]).bind(&world).nav();
+        assert_eq!(
+            bound.ancestor_at::<ParentRef>(2).map(|b| b.entity()),
+            Some(chain[0])
+        );
+        assert!(bound.ancestor_at::<ParentRef>(3).is_none());
+
+        // A cycle ends the chain instead of looping
+        let cyclic = EntityHandle::new(y).bind(&world).nav();
+        assert_eq!(
+            cyclic.ancestor_at::<ParentRef>(1).map(|b| b.entity()),
+            Some(x)
+        );
+        assert!(cyclic.ancestor_at::<ParentRef>(5).is_none());
+    }
+
+    #[test]
+    fn ancestors_stop_on_cycle() {
+        let mut world = World::new();
+        let x = world.spawn_empty().id();
+        let y = world.spawn(ParentRef(Some(EntityHandle::new(x)))).id();
+        world
+            .entity_mut(x)
+            .insert(ParentRef(Some(EntityHandle::new(y))));
+        let z = world.spawn(ParentRef(Some(EntityHandle::new(y)))).id();
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let from_y: Vec<_> = world_ref
+            .entity(y)
+            .nav()
+            .ancestors::<ParentRef>()
+            .map(|p| p.entity())
+            .collect();
+        assert_eq!(from_y, vec![x]);
+
+        let from_z: Vec<_> = world_ref
+            .entity(z)
+            .nav()
+            .ancestors::<ParentRef>()
+            .map(|p| p.entity())
+            .collect();
+        assert_eq!(from_z, vec![y, x]);
+
+        assert!(
+            world_ref
+                .entity(z)
+                .nav()
+                .ancestor_until::<ParentRef, _>(|_| false)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn descendants_post_order_children_first() {
+        let mut world = World::new();
+        let [root, a, b, c] = spawn_small_tree(&mut world);
+        // Extend: b -> [d, e], d -> [f]
+        let f = world.spawn(Name("f")).id();
+        let d = world
+            .spawn((Name("d"), ChildRefs(vec![EntityHandle::new(f)])))
+            .id();
+        let e = world.spawn(Name("e")).id();
+        world
+            .entity_mut(b)
+            .insert(ChildRefs(vec![EntityHandle::new(d), EntityHandle::new(e)]));
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let order: Vec<_> = world_ref
+            .entity(root)
+            .nav_many()
+            .descendants_post_order::<ChildRefs>()
+            .map(|p| p.entity())
+            .collect();
+        assert_eq!(order, vec![c, a, f, d, e, b]);
+
+        // Leaf has no descendants
+        assert_eq!(
+            world_ref
+                .entity(c)
+                .nav_many()
+                .descendants_post_order::<ChildRefs>()
+                .count(),
+            0
+        );
+
+        let bound: Vec<_> = EntityHandle::new(b)
+            .bind(&world)
+            .nav()
+            .descendants_post_order::<ChildRefs>()
+            .map(|p| p.entity())
+            .collect();
+        assert_eq!(bound, vec![f, d, e]);
+    }
+
+    #[test]
+    fn get_inherited_from_grandparent() {
+        let mut world = World::new();
+        let [root, a, b, c] = spawn_small_tree(&mut world);
+        for (child, parent) in [(a, root), (b, root), (c, a)] {
+            world
+                .entity_mut(child)
+                .insert(ParentRef(Some(EntityHandle::new(parent))));
+        }
+        world.entity_mut(root).insert(Health(100));
+
+        {
+            // SAFETY: world outlives usage and is not mutated until this block ends
+            let world_ref = unsafe { WorldRef::new(&world) };
+            let inherited = |e| {
+                world_ref
+                    .entity(e)
+                    .nav()
+                    .get_inherited::<Health, ParentRef>()
+                    .map(|h| h.0)
+            };
+            assert_eq!(inherited(c), Some(100));
+            assert_eq!(inherited(root), Some(100));
+            // Nothing to inherit along this chain
+            assert!(
+                world_ref
+                    .entity(c)
+                    .nav()
+                    .get_inherited::<Damage, ParentRef>()
+                    .is_none()
+            );
+        }
+
+        // The nearest value wins
+        world.entity_mut(a).insert(Health(7));
+        let bound = EntityHandle::new(c).bind(&world).nav();
+        assert_eq!(
+            bound.get_inherited::<Health, ParentRef>().map(|h| h.0),
+            Some(7)
+        );
+    }
+
+    #[test]
+    fn collect_field_preorder_skips_missing() {
+        let mut world = World::new();
+        let [root, a, _b, c] = spawn_small_tree(&mut world);
+        world.entity_mut(root).insert(Health(10));
+        world.entity_mut(c).insert(Health(2));
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let nav = world_ref.entity(root).nav_many();
+        assert_eq!(
+            nav.collect_field_preorder::<ChildRefs, Name, _, _>(|n| n.0),
+            vec!["root", "a", "c", "b"]
+        );
+        assert_eq!(
+            nav.collect_field_preorder::<ChildRefs, Health, _, _>(|h| h.0),
+            vec![10, 2]
+        );
+
+        let bound = EntityHandle::new(a).bind(&world).nav();
+        assert_eq!(
+            bound.collect_field_preorder::<ChildRefs, Name, _, _>(|n| n.0),
+            vec!["a", "c"]
+        );
+    }
+
+    #[test]
+    fn reduce_children_strongest() {
+        let mut world = World::new();
+        let [root, a, b, _c] = spawn_small_tree(&mut world);
+        world.entity_mut(a).insert(Health(30));
+        world.entity_mut(b).insert(Health(45));
+
+        let health = |h: Option<&Health>| h.map_or(0, |h| h.0);
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let strongest = world_ref
+            .entity(root)
+            .nav_many()
+            .reduce_children::<ChildRefs, _>(|x, y| {
+                if health(y.get::<Health>()) > health(x.get::<Health>()) {
+                    y
+                } else {
+                    x
+                }
+            });
+        assert_eq!(strongest.map(|p| p.entity()), Some(b));
+
+        // No children
+        assert!(
+            world_ref
+                .entity(b)
+                .nav_many()
+                .reduce_children::<ChildRefs, _>(|x, _| x)
+                .is_none()
+        );
+
+        let bound = EntityHandle::new(root)
+            .bind(&world)
+            .nav()
+            .reduce_children::<ChildRefs, _>(|x, y| {
+                if health(y.get::<Health>()) < health(x.get::<Health>()) {
+                    y
+                } else {
+                    x
+                }
+            });
+        assert_eq!(bound.map(|p| p.entity()), Some(a));
+    }
+
+    #[test]
+    fn diff_children_sets() {
+        let mut world = World::new();
+        let [root, a, b, c] = spawn_small_tree(&mut world);
+        let d = world.spawn(Name("d")).id();
+        let edited = world
+            .spawn(ChildRefs(vec![
+                EntityHandle::new(d),
+                EntityHandle::new(b),
+                EntityHandle::new(c),
+            ]))
+            .id();
+        let reordered = world
+            .spawn(ChildRefs(vec![EntityHandle::new(b), EntityHandle::new(a)]))
+            .id();
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let diff = diff_children::<ChildRefs>(world_ref.entity(root), world_ref.entity(edited));
+        assert_eq!(diff.added, vec![d, c]);
+        assert_eq!(diff.removed, vec![a]);
+        assert_eq!(diff.common, vec![b]);
+        assert!(!diff.is_unchanged());
+
+        let diff = diff_children::<ChildRefs>(world_ref.entity(root), world_ref.entity(reordered));
+        assert!(diff.is_unchanged());
+        assert_eq!(diff.common, vec![a, b]);
+
+        // Missing component on one side counts as no children
+        let diff = diff_children::<ChildRefs>(world_ref.entity(d), world_ref.entity(root));
+        assert_eq!(diff.added, vec![a, b]);
+        assert!(diff.removed.is_empty() && diff.common.is_empty());
+    }
+
+    #[test]
+    fn union_children_overlapping_sets() {
+        let mut world = World::new();
+        let [root, a, b, c] = spawn_small_tree(&mut world);
+        let d = world.spawn(Name("d")).id();
+        let overlay = world
+            .spawn(ChildRefs(vec![
+                EntityHandle::new(c),
+                EntityHandle::new(b),
+                EntityHandle::new(d),
+                EntityHandle::new(c),
+            ]))
+            .id();
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let entities = |ptrs: Vec<EntityPtr>| ptrs.iter().map(|p| p.entity()).collect::<Vec<_>>();
+        let merged = union_children::<ChildRefs>(world_ref.entity(root), world_ref.entity(overlay));
+        assert_eq!(entities(merged), vec![a, b, c, d]);
+
+        // Missing component on either side counts as no children
+        let merged = union_children::<ChildRefs>(world_ref.entity(d), world_ref.entity(root));
+        assert_eq!(entities(merged), vec![a, b]);
+    }
+
+    #[test]
+    fn collect_subtree_map_diamond_once() {
+        let mut world = World::new();
+        let [root, a, b, c] = spawn_small_tree(&mut world);
+        // Diamond: c is reachable via a and b
+        world
+            .entity_mut(b)
+            .insert(ChildRefs(vec![EntityHandle::new(c)]));
+        for (entity, hp) in [(root, 1), (a, 2), (b, 3), (c, 4)] {
+            world.entity_mut(entity).insert(Health(hp));
+        }
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let mut calls = 0;
+        let map = world_ref
+            .entity(root)
+            .nav_many()
+            .collect_subtree_map::<ChildRefs, _, _>(|node| {
+                calls += 1;
+                node.get::<Health>().unwrap().0
+            });
+        assert_eq!(calls, 4);
+        assert_eq!(map.len(), 4);
+        assert_eq!((map[&root], map[&a], map[&b], map[&c]), (1, 2, 3, 4));
+
+        let bound = EntityHandle::new(b)
+            .bind(&world)
+            .nav()
+            .collect_subtree_map::<ChildRefs, _, _>(|node| node.get::<Name>().unwrap().0);
+        assert_eq!(bound.len(), 2);
+        assert_eq!(bound[&c], "c");
+    }
+
+    #[test]
+    fn common_root_of_selection() {
+        let mut world = World::new();
+        let [root, a, b, c] = spawn_small_tree(&mut world);
+        let d = world.spawn(ParentRef(Some(EntityHandle::new(a)))).id();
+        for (child, parent) in [(a, root), (b, root), (c, a)] {
+            world
+                .entity_mut(child)
+                .insert(ParentRef(Some(EntityHandle::new(parent))));
+        }
+        let stranger = world.spawn(Name("stranger")).id();
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let ptrs =
+            |es: &[Entity]| -> Vec<EntityPtr> { es.iter().map(|&e| world_ref.entity(e)).collect() };
+        let root_of = |es: &[Entity]| common_root::<ParentRef>(&ptrs(es)).map(|p| p.entity());
+
+        assert_eq!(root_of(&[c, d, b]), Some(root));
+        assert_eq!(root_of(&[c, d]), Some(a));
+        // A selected entity containing the others is the root
+        assert_eq!(root_of(&[c, a, d]), Some(a));
+        assert_eq!(root_of(&[c]), Some(c));
+        assert_eq!(root_of(&[c, stranger]), None);
+        assert_eq!(root_of(&[]), None);
+    }
+
+    #[test]
+    fn level_sizes_balanced_and_small() {
+        let mut world = World::new();
+        let balanced = spawn_binary_tree(&mut world, 4);
+        let [root, a, b, _c] = spawn_small_tree(&mut world);
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        assert_eq!(
+            world_ref
+                .entity(balanced)
+                .nav_many()
+                .level_sizes::<ChildRefs>(),
+            vec![1, 2, 4, 8, 16]
+        );
+        assert_eq!(
+            world_ref.entity(root).nav_many().level_sizes::<ChildRefs>(),
+            vec![1, 2, 1]
+        );
+        assert_eq!(
+            world_ref.entity(b).nav_many().level_sizes::<ChildRefs>(),
+            vec![1]
+        );
+
+        let bound = EntityHandle::new(a).bind(&world).nav();
+        assert_eq!(bound.level_sizes::<ChildRefs>(), vec![1, 1]);
+    }
+
+    #[test]
+    fn descendants_entities_dfs_and_bfs() {
+        let mut world = World::new();
+        let [root, a, b, c] = spawn_small_tree(&mut world);
+
+        {
+            // SAFETY: world outlives usage and is not mutated until this block ends
+            let world_ref = unsafe { WorldRef::new(&world) };
+            let nav = world_ref.entity(root).nav_many();
+            assert_eq!(nav.descendants_entities_dfs::<ChildRefs>(), vec![a, c, b]);
+            assert_eq!(nav.descendants_entities_bfs::<ChildRefs>(), vec![a, b, c]);
+        }
+
+        let leaf = EntityHandle::new(c).bind(&world).nav();
+        assert!(leaf.descendants_entities_dfs::<ChildRefs>().is_empty());
+        assert!(leaf.descendants_entities_bfs::<ChildRefs>().is_empty());
+
+        // The IDs outlive the read phase and drive mutation afterwards
+        let doomed = EntityHandle::new(a)
+            .bind(&world)
+            .nav()
+            .descendants_entities_bfs::<ChildRefs>();
+        for entity in doomed {
+            world.despawn(entity);
+        }
+        assert!(world.get_entity(c).is_err());
+        assert!(world.get_entity(b).is_ok());
+    }
+
+    #[test]
+    fn descendant_handles_are_storable() {
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+        let mut world = World::new();
+        let [root, a, b, c] = spawn_small_tree(&mut world);
+
+        let with_root = {
+            // SAFETY: world outlives usage and is not mutated until this block ends
+            let world_ref = unsafe { WorldRef::new(&world) };
+            let nav = world_ref.entity(root).nav_many();
+            assert_eq!(
+                nav.descendant_handles::<ChildRefs>(false),
+                [a, c, b].map(EntityHandle::new).to_vec()
+            );
+            nav.descendant_handles::<ChildRefs>(true)
+        };
+        assert_send_sync(&with_root);
+        assert_eq!(with_root, [root, a, c, b].map(EntityHandle::new).to_vec());
+
+        let bound = EntityHandle::new(a).bind(&world).nav();
+        assert_eq!(
+            bound.descendant_handles::<ChildRefs>(false),
+            vec![EntityHandle::new(c)]
+        );
+
+        // The handles can be stored back into the world
+        let backup = world.spawn(ChildRefs(with_root)).id();
+        assert_eq!(world.get::<ChildRefs>(backup).unwrap().0.len(), 4);
+    }
+
+    #[test]
+    fn find_path_to_named_leaf() {
+        let mut world = World::new();
+        let [root, a, b, c] = spawn_small_tree(&mut world);
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let named = |name: &'static str| move |p: EntityPtr| p.get::<Name>().unwrap().0 == name;
+        let nav = world_ref.entity(root).nav_many();
+
+        let path = nav.find_path_to::<ChildRefs, _>(named("c")).unwrap();
+        assert_eq!(
+            path.iter().map(|p| p.entity()).collect::<Vec<_>>(),
+            vec![root, a, c]
+        );
+        let path = nav.find_path_to::<ChildRefs, _>(named("b")).unwrap();
+        assert_eq!(
+            path.iter().map(|p| p.entity()).collect::<Vec<_>>(),
+            vec![root, b]
+        );
+
+        // The root itself is never a match
+        assert!(nav.find_path_to::<ChildRefs, _>(named("root")).is_none());
+        assert!(nav.find_path_to::<ChildRefs, _>(|_| false).is_none());
+
+        let bound = EntityHandle::new(root)
+            .bind(&world)
+            .nav()
+            .find_path_to::<ChildRefs, _>(|p| !p.has::<ChildRefs>())
+            .unwrap();
+        // Breadth-first: the shallow leaf `b` is found before `c`
+        assert_eq!(
+            bound.iter().map(|p| p.entity()).collect::<Vec<_>>(),
+            vec![root, b]
+        );
+    }
+
+    #[test]
+    fn child_edges_cover_every_link() {
+        let mut world = World::new();
+        let [root, a, b, c] = spawn_small_tree(&mut world);
+        let tree = spawn_binary_tree(&mut world, 3);
+
+        // SAFETY: world outlives usage and is not mutated after this point
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let edges: Vec<_> = world_ref
+            .entity(root)
+            .nav_many()
+            .child_edges::<ChildRefs>()
+            .collect();
+        assert_eq!(edges, vec![(root, a), (root, b), (a, c)]);
+
+        let bound = EntityHandle::new(tree).bind(&world).nav();
+        let edges: Vec<_> = bound.child_edges::<ChildRefs>().collect();
+        // A tree has one edge per non-root node
+        assert_eq!(edges.len(), bound.descendants::<ChildRefs>().count());
+        assert_eq!(edges.len(), 14);
+        assert!(edges.iter().all(|&(parent, child)| {
+            world
+                .get::<ChildRefs>(parent)
+                .unwrap()
+                .0
+                .contains(&EntityHandle::new(child))
+        }));
+    }
+
+    #[test]
+    fn find_path_to_survives_cycle() {
+        let mut world = World::new();
+        let x = world.spawn_empty().id();
+        let y = world.spawn(ChildRefs(vec![EntityHandle::new(x)])).id();
+        world
+            .entity_mut(x)
+            .insert(ChildRefs(vec![EntityHandle::new(y)]));
+
+        // SAFETY: world outlives usage
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let mut tested = 0;
+        let found = world_ref
+            .entity(x)
+            .nav_many()
+            .find_path_to::<ChildRefs, _>(|_| {
+                tested += 1;
+                false
+            });
+        assert!(found.is_none());
+        assert_eq!(tested, 1);
     }
 }
@@ -3,11 +3,25 @@
 //! This module provides an ergonomic API that avoids repeatedly passing `&World` by
 //! transmuting the lifetime to `'static`. The single unsafe point is `WorldRef::new()`.
 
-use bevy_ecs::component::Component;
-use bevy_ecs::entity::Entity;
+use std::collections::{HashSet, VecDeque};
+
+use bevy_ecs::change_detection::Ref;
+use bevy_ecs::component::{Component, ComponentId};
+use bevy_ecs::entity::{Entity, EntityLocation};
+use bevy_ecs::entity_disabling::Disabled;
+use bevy_ecs::query::{Allow, With};
+use bevy_ecs::relationship::{Relationship, RelationshipTarget};
 use bevy_ecs::world::World;
 
-use crate::handle::EntityHandle;
+use crate::debug::{DebugComponents, DebugEntity};
+use crate::error::EntityAccessError;
+use crate::guard::GuardedPtr;
+use crate::handle::{
+    EntityHandle, EntitySnapshot, HandleContainer, Named, TypedEntityHandle, WorldScope,
+};
+#[cfg(feature = "nav-traits")]
+use crate::nav::HasChildren;
+use crate::snapshot::CloneComponents;
 
 /// A reference to a World with erased lifetime for ergonomic entity traversal.
 ///
@@ -52,6 +66,16 @@ impl WorldRef {
         }
     }
 
+    /// Creates a `WorldScope` that keeps the real lifetime of `world`.
+    ///
+    /// This is the safe alternative to `WorldRef::new()` for code outside Bevy systems:
+    /// the scope offers the same entry points but yields `BoundEntity<'w>`, so the
+    /// borrow checker enforces the outlive and no-mutation rules instead of the caller.
+    #[inline]
+    pub const fn new_scoped(world: &World) -> WorldScope<'_> {
+        WorldScope::new(world)
+    }
+
     /// Gets an EntityPtr for the given entity.
     ///
     /// Returns an `EntityPtr` regardless of whether the entity exists.
@@ -74,6 +98,18 @@ impl WorldRef {
         }
     }
 
+    /// Gets an EntityPtr from a raw entity index, if that entity is alive.
+    ///
+    /// Builds the entity with `Entity::from_raw_u32`, i.e. at its first generation, so
+    /// an index whose slot has since been recycled returns `None` rather than
+    /// silently resolving to a different entity. Also returns `None` for the
+    /// invalid index `u32::MAX`.
+    #[inline]
+    #[must_use]
+    pub fn entity_from_raw_u32(&self, bits: u32) -> Option<EntityPtr> {
+        self.entity_opt(Entity::from_raw_u32(bits)?)
+    }
+
     /// Creates an EntityPtr from an EntityHandle.
     #[inline]
     pub fn from_handle(&self, handle: EntityHandle) -> EntityPtr {
@@ -88,6 +124,148 @@ impl WorldRef {
         self.world.get::<T>(entity)
     }
 
+    /// Returns the number of spawned entities in the world.
+    ///
+    /// Counts live entities only (despawned ones are excluded). This walks the
+    /// entity metadata, so it is meant for diagnostics such as debug overlays.
+    #[inline]
+    #[must_use]
+    pub fn entity_count(&self) -> usize {
+        self.world.entity_count() as usize
+    }
+
+    /// Returns `true` if the world has no spawned entities.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        !self.world.entities().any_spawned()
+    }
+
+    /// Returns how many live entities have component `C`.
+    ///
+    /// Runs a transient `With<C>` query, so it is meant for diagnostics such as a
+    /// "what's in my world" debug command. Like `entity_count()` and `find()`, this
+    /// includes `Disabled` entities. Returns 0 if `C` was never registered.
+    #[must_use]
+    pub fn count_with<C: Component>(&self) -> usize {
+        self.world
+            .try_query_filtered::<(), (With<C>, Allow<Disabled>)>()
+            .map_or(0, |mut query| query.iter(self.world).count())
+    }
+
+    /// Returns the first live entity for which `pred` returns `true`.
+    ///
+    /// Scans entities in storage order and stops at the first match, e.g. finding
+    /// the player by name. Entities marked `Disabled` are scanned too, matching
+    /// `entity_count()`. This visits every entity in the worst case (O(n)), so
+    /// prefer a query when the filter is static.
+    pub fn find<P: FnMut(EntityPtr) -> bool>(&self, mut pred: P) -> Option<EntityPtr> {
+        let mut query = self.world.try_query_filtered::<Entity, Allow<Disabled>>()?;
+        query
+            .iter(self.world)
+            .map(|e| EntityPtr::new(e, self.world))
+            .find(|&p| pred(p))
+    }
+
+    /// Returns `true` if any live entity satisfies `pred`.
+    ///
+    /// The boolean complement to `find()`: stops at the first match, e.g. checking
+    /// whether anything targets an entity without collecting the attackers. Like
+    /// `find()`, this includes `Disabled` entities and is O(n) in the worst case.
+    pub fn any<P: FnMut(EntityPtr) -> bool>(&self, pred: P) -> bool {
+        self.find(pred).is_some()
+    }
+
+    /// Gets one component from each of two entities.
+    ///
+    /// Convenience for interactions such as attacker/defender without creating two
+    /// `EntityPtr`s. Access is read-only, so `a` and `b` may be the same entity.
+    #[inline]
+    pub fn get_pair<A: Component, B: Component>(
+        &self,
+        a: Entity,
+        b: Entity,
+    ) -> (Option<&A>, Option<&B>) {
+        (self.world.get::<A>(a), self.world.get::<B>(b))
+    }
+
+    /// Gets the same component type from two entities.
+    ///
+    /// Shorthand for `get_pair::<T, T>(a, b)`.
+    #[inline]
+    pub fn get_pair_same<T: Component>(&self, a: Entity, b: Entity) -> (Option<&T>, Option<&T>) {
+        self.get_pair::<T, T>(a, b)
+    }
+
+    /// Applies navigation steps in sequence starting from `start`.
+    ///
+    /// Each step maps the current pointer to the next; the first `None` ends the
+    /// walk. With no steps this returns the start pointer. Suits data-driven
+    /// navigation where a path is described as a list of step functions.
+    #[must_use]
+    pub fn resolve_path(
+        &self,
+        start: Entity,
+        steps: &[fn(EntityPtr) -> Option<EntityPtr>],
+    ) -> Option<EntityPtr> {
+        steps
+            .iter()
+            .try_fold(self.entity(start), |current, step| step(current))
+    }
+
+    /// Reads `T` from each handle and maps it through `f`, preserving order.
+    ///
+    /// `f` receives `None` for handles whose entity is despawned or lacks `T`, so
+    /// the result always has one entry per handle.
+    pub fn gather<T, R, F>(&self, handles: &[EntityHandle], mut f: F) -> Vec<R>
+    where
+        T: Component,
+        F: FnMut(Option<&T>) -> R,
+    {
+        handles
+            .iter()
+            .map(|h| f(self.world.get::<T>(h.entity())))
+            .collect()
+    }
+
+    /// Checks that every handle in a cached path still refers to a live entity.
+    ///
+    /// An empty path is trivially valid.
+    #[must_use]
+    pub fn validate_path(&self, path: &[EntityHandle]) -> bool {
+        path.iter().all(|h| h.is_alive(self.world))
+    }
+
+    /// Walks `follow`-style links from `start` and checks they visit `expected` in order.
+    ///
+    /// `expected` lists the entities after `start`. Each hop reads `T` on the current
+    /// entity and follows the handle `f` extracts; the walk must land on a live
+    /// entity equal to the next expected one. Returns `false` if `start` is
+    /// despawned, and at the first missing link, despawned entity or mismatch.
+    /// Links past the end of `expected` are not checked.
+    #[must_use]
+    pub fn validate_linked_path<T, F>(&self, start: Entity, mut f: F, expected: &[Entity]) -> bool
+    where
+        T: Component,
+        F: FnMut(&T) -> EntityHandle,
+    {
+        if self.world.get_entity(start).is_err() {
+            return false;
+        }
+        let mut current = start;
+        for &next in expected {
+            let Some(link) = self.world.get::<T>(current) else {
+                return false;
+            };
+            let handle = f(link);
+            if handle.entity() != next || !handle.is_alive(self.world) {
+                return false;
+            }
+            current = next;
+        }
+        true
+    }
+
     /// Returns the underlying World reference.
     ///
     /// This can be used to access World methods directly when needed.
@@ -157,6 +335,22 @@ impl EntityPtr {
         self.world.get::<T>(self.entity)
     }
 
+    /// Gets a component together with the world it was read from.
+    ///
+    /// A power-user escape hatch for call sites that need a component and direct
+    /// `&World` access, e.g. for a one-off raw query. Returns `None` if the entity
+    /// doesn't exist or doesn't have the component.
+    ///
+    /// # Lifetime Note
+    /// Both references are `'static`, with the same caveat as `get`: they are only
+    /// valid while the `WorldRef` safety contract holds, so never keep them beyond
+    /// the system that created this pointer.
+    #[inline]
+    #[must_use]
+    pub fn get_and_world<T: Component>(self) -> Option<(&'static T, &'static World)> {
+        self.get::<T>().map(|c| (c, self.world))
+    }
+
     /// Checks if this entity has a component of type `T`.
     ///
     /// Returns `false` if the entity doesn't exist.
@@ -165,12 +359,214 @@ impl EntityPtr {
         self.world.get::<T>(self.entity).is_some()
     }
 
+    /// Gets component `T` from both this entity and `other`.
+    ///
+    /// Returns `None` unless both entities have the component, e.g. when comparing
+    /// an attacker's and a target's `Health`.
+    #[inline]
+    #[must_use]
+    pub fn zip_get<T: Component>(self, other: EntityPtr) -> Option<(&'static T, &'static T)> {
+        self.get::<T>().zip(other.get::<T>())
+    }
+
+    /// Borrows a slice stored inside component `T`.
+    ///
+    /// `f` projects the component to one of its inner slices, e.g. a `Vec` of
+    /// handles or Bevy's `Children`, which is returned without cloning. Returns
+    /// `None` if the entity doesn't exist or doesn't have the component.
+    #[inline]
+    #[must_use]
+    pub fn get_slice<T, E, F>(self, f: F) -> Option<&'static [E]>
+    where
+        T: Component,
+        F: FnOnce(&T) -> &[E],
+    {
+        self.get::<T>().map(f)
+    }
+
+    /// Formats a component with its `Display` impl.
+    ///
+    /// Returns `None` if the entity doesn't exist or doesn't have the component.
+    #[inline]
+    #[must_use]
+    pub fn display_component<T: Component + std::fmt::Display>(self) -> Option<String> {
+        self.get::<T>().map(ToString::to_string)
+    }
+
+    /// Gets a clone of `T`, falling back to the last value seen when it is absent.
+    ///
+    /// A caching convenience for systems that tolerate a frame of staleness: when
+    /// the entity has `T`, `last` is updated and the fresh clone returned; when the
+    /// entity or component is currently missing, the cached `last` is returned
+    /// instead (or `None` if nothing was ever seen). The cache is never cleared by
+    /// this method, so reset `last` yourself once staleness is no longer acceptable.
+    #[inline]
+    pub fn get_or_last<T: Component + Clone>(self, last: &mut Option<T>) -> Option<T> {
+        if let Some(current) = self.get::<T>() {
+            *last = Some(current.clone());
+        }
+        last.clone()
+    }
+
+    /// Gets change-detection `Ref`s to two components at once.
+    ///
+    /// Returns `None` unless the entity exists and has both components. Each `Ref`
+    /// reports `is_changed()` / `is_added()` relative to the world's last change tick.
+    #[inline]
+    #[must_use]
+    pub fn get_ref2<A: Component, B: Component>(
+        self,
+    ) -> Option<(Ref<'static, A>, Ref<'static, B>)> {
+        let entity = self.world.get_entity(self.entity).ok()?;
+        Some((entity.get_ref::<A>()?, entity.get_ref::<B>()?))
+    }
+
+    /// Gets the name stored in the `Named` component `N`.
+    ///
+    /// Returns `None` if the entity doesn't exist or doesn't have `N`.
+    #[inline]
+    #[must_use]
+    pub fn name_via<N: Component + Named>(self) -> Option<&'static str> {
+        self.get::<N>().map(Named::name)
+    }
+
+    /// Reads a persistent identity from the user-provided stable-id component `S`.
+    ///
+    /// `Entity` IDs are not stable across runs, so editors and serialization
+    /// pipelines usually store their own ID component. Any `Copy` component that
+    /// converts into `u64` works. Returns `None` if the entity doesn't exist or
+    /// doesn't have `S`.
+    #[inline]
+    #[must_use]
+    pub fn stable_id<S: Component + Into<u64> + Copy>(self) -> Option<u64> {
+        self.get::<S>().map(|&id| id.into())
+    }
+
+    /// Returns a `Debug` view printing the values of the components in `C`.
+    ///
+    /// `C` is a tuple of up to four `Component + Debug` types, e.g.
+    /// `ptr.debug_view::<(Name, Health)>()`. See `DebugEntity`.
+    #[inline]
+    pub const fn debug_view<C: DebugComponents>(self) -> DebugEntity<C> {
+        DebugEntity::new(self)
+    }
+
+    /// Clones the components in `C` into an owned tuple.
+    ///
+    /// `C` is a tuple of up to four `Component + Clone` types, e.g.
+    /// `ptr.snapshot_components::<(Health, Armor)>()`. Returns `None` unless the
+    /// entity exists and has all of them. Unlike `EntityPtr`, the result holds no
+    /// world reference, so it stays valid after the World changes. See `CloneComponents`.
+    #[inline]
+    #[must_use]
+    pub fn snapshot_components<C: CloneComponents>(self) -> Option<C> {
+        C::clone_components(self.world, self.entity)
+    }
+
     /// Checks if this entity is still alive.
     #[inline]
     pub fn is_alive(self) -> bool {
         self.world.get_entity(self.entity).is_ok()
     }
 
+    /// Returns `self` if this entity is alive, panicking otherwise.
+    ///
+    /// Meant for tests and setup code where a despawned entity is a bug: the
+    /// panic message is `msg` followed by the entity ID, so spawn mistakes fail
+    /// loudly at the point of use.
+    ///
+    /// # Panics
+    /// Panics if the entity has been despawned.
+    #[inline]
+    #[track_caller]
+    pub fn expect_alive(self, msg: &str) -> Self {
+        assert!(
+            self.is_alive(),
+            "{msg}: entity {} is not alive",
+            self.entity
+        );
+        self
+    }
+
+    /// Captures an `EntitySnapshot` if this entity is alive.
+    ///
+    /// Lets a traversal check liveness once and pass the result along instead of
+    /// calling `is_alive()` repeatedly.
+    #[inline]
+    #[must_use]
+    pub fn snapshot(self) -> Option<EntitySnapshot> {
+        self.is_alive().then_some(EntitySnapshot::new(self.entity))
+    }
+
+    /// Runs `f` on this pointer only if the entity is still alive.
+    ///
+    /// Returns `None` without calling `f` if the entity has been despawned,
+    /// centralizing the liveness check that helper functions otherwise repeat.
+    #[inline]
+    pub fn and_then_if_alive<R, F: FnOnce(EntityPtr) -> Option<R>>(self, f: F) -> Option<R> {
+        if self.is_alive() { f(self) } else { None }
+    }
+
+    /// Returns the entity's storage location (archetype, table and rows).
+    ///
+    /// Returns `None` if the entity is despawned. Useful when profiling how a
+    /// traversal hops between archetypes and tables.
+    #[inline]
+    #[must_use]
+    pub fn location(self) -> Option<EntityLocation> {
+        self.world.entities().get_spawned(self.entity).ok()
+    }
+
+    /// Checks whether this entity and `other` share an archetype.
+    ///
+    /// Entities in the same archetype have exactly the same set of components, so
+    /// this is a cheap way to batch structurally identical entities during a
+    /// traversal. Returns `false` if either entity is despawned or the two pointers
+    /// read from different worlds, whose archetype IDs are unrelated.
+    #[inline]
+    pub fn same_archetype(self, other: EntityPtr) -> bool {
+        if self.merge_world(other).is_none() {
+            return false;
+        }
+        match (self.location(), other.location()) {
+            (Some(a), Some(b)) => a.archetype_id == b.archetype_id,
+            _ => false,
+        }
+    }
+
+    /// Returns how many components this entity has.
+    ///
+    /// Read from the entity's archetype without allocating; returns 0 if the entity
+    /// is despawned. Handy when profiling how heavy the entities on a traversal are.
+    #[inline]
+    pub fn component_count(self) -> usize {
+        self.world
+            .get_entity(self.entity)
+            .map_or(0, |entity| entity.archetype().component_count())
+    }
+
+    /// Returns the IDs of this entity's components that pass `predicate`.
+    ///
+    /// A building block for inspector views with a "pinned components" filter.
+    /// Returns an empty `Vec` if the entity is despawned.
+    pub fn component_ids_filtered(
+        self,
+        mut predicate: impl FnMut(ComponentId) -> bool,
+    ) -> Vec<ComponentId> {
+        self.world
+            .get_entity(self.entity)
+            .map(|entity| {
+                entity
+                    .archetype()
+                    .components()
+                    .iter()
+                    .copied()
+                    .filter(|&id| predicate(id))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Follows a reference component to another entity.
     ///
     /// The component must contain an `EntityHandle`. Use `follow_opt` for optional references.
@@ -187,6 +583,23 @@ impl EntityPtr {
             .map(|c| EntityPtr::new(f(c).entity(), self.world))
     }
 
+    /// Follows the handle stored in `L` and fetches `T` on the target, returning both.
+    ///
+    /// A one-hop join: the local link component and the target's data in one call.
+    /// Returns `None` if this entity lacks `L` or the target lacks `T`.
+    #[inline]
+    #[must_use]
+    pub fn follow_get<L, T, F>(self, f: F) -> Option<(&'static L, &'static T)>
+    where
+        L: Component,
+        T: Component,
+        F: FnOnce(&L) -> EntityHandle,
+    {
+        let link = self.get::<L>()?;
+        let target = self.world.get::<T>(f(link).entity())?;
+        Some((link, target))
+    }
+
     /// Follows an optional reference component to another entity.
     ///
     /// The extractor function returns `Option<EntityHandle>`.
@@ -203,6 +616,198 @@ impl EntityPtr {
             .and_then(|c| f(c).map(|h| EntityPtr::new(h.entity(), self.world)))
     }
 
+    /// Follows an optional reference, falling back to `default` when it is unset.
+    ///
+    /// Returns a pointer to `default` if this entity doesn't have the component or
+    /// the extractor returns `None`, e.g. falling back to a default camera or the
+    /// scene root. The result is not checked for liveness.
+    #[inline]
+    pub fn follow_opt_or<T, F>(self, f: F, default: Entity) -> EntityPtr
+    where
+        T: Component,
+        F: FnOnce(&T) -> Option<EntityHandle>,
+    {
+        self.follow_opt::<T, F>(f)
+            .unwrap_or(EntityPtr::new(default, self.world))
+    }
+
+    /// Follows a typed reference, checking that the target has the tagged component `M`.
+    ///
+    /// Returns `None` if this entity doesn't have `T` or the target lacks `M`, so a
+    /// returned pointer is guaranteed to have `M` for the rest of the read.
+    #[inline]
+    #[must_use]
+    pub fn follow_typed<T, M, F>(self, f: F) -> Option<EntityPtr>
+    where
+        T: Component,
+        M: Component,
+        F: FnOnce(&T) -> TypedEntityHandle<M>,
+    {
+        self.get::<T>()
+            .map(|c| EntityPtr::new(f(c).entity(), self.world))
+            .filter(|target| target.has::<M>())
+    }
+
+    /// Like `follow`, but reports why the follow failed.
+    ///
+    /// Returns `EntityAccessError::Despawned` if this entity no longer exists and
+    /// `EntityAccessError::ComponentMissing` if it doesn't have `T`, so traversal
+    /// functions returning `Result` can use `?`.
+    #[inline]
+    pub fn follow_res<T, F>(self, f: F) -> Result<EntityPtr, EntityAccessError>
+    where
+        T: Component,
+        F: FnOnce(&T) -> EntityHandle,
+    {
+        self.get_res::<T>()
+            .map(|c| EntityPtr::new(f(c).entity(), self.world))
+    }
+
+    /// Like `follow_opt`, but reports why the follow failed.
+    ///
+    /// In addition to the `follow_res` errors, returns `EntityAccessError::LinkEmpty`
+    /// when the component is present but the extractor returns `None`.
+    #[inline]
+    pub fn follow_opt_res<T, F>(self, f: F) -> Result<EntityPtr, EntityAccessError>
+    where
+        T: Component,
+        F: FnOnce(&T) -> Option<EntityHandle>,
+    {
+        let handle = f(self.get_res::<T>()?).ok_or(EntityAccessError::LinkEmpty {
+            entity: self.entity,
+            component: std::any::type_name::<T>(),
+        })?;
+        Ok(EntityPtr::new(handle.entity(), self.world))
+    }
+
+    /// Gets a component, distinguishing a despawned entity from a missing component.
+    fn get_res<T: Component>(self) -> Result<&'static T, EntityAccessError> {
+        let entity =
+            self.world
+                .get_entity(self.entity)
+                .map_err(|_| EntityAccessError::Despawned {
+                    entity: self.entity,
+                })?;
+        entity
+            .get::<T>()
+            .ok_or(EntityAccessError::ComponentMissing {
+                entity: self.entity,
+                component: std::any::type_name::<T>(),
+            })
+    }
+
+    /// Follows a component holding multiple entity references.
+    ///
+    /// The extractor function returns a slice of `EntityHandle`s borrowed from the component.
+    ///
+    /// Returns an empty iterator if this entity doesn't have the component.
+    #[inline]
+    pub fn follow_many<T, F>(self, f: F) -> impl Iterator<Item = EntityPtr>
+    where
+        T: Component,
+        F: FnOnce(&T) -> &[EntityHandle],
+    {
+        let world = self.world;
+        self.get::<T>()
+            .map(f)
+            .unwrap_or(&[])
+            .iter()
+            .map(move |h| EntityPtr::new(h.entity(), world))
+    }
+
+    /// Like `follow_many`, but yields each distinct target once.
+    ///
+    /// Duplicate handles are skipped, keeping the order of first occurrence. Useful
+    /// when a component's handle list may repeat, e.g. a target appearing twice in
+    /// an attack queue.
+    #[inline]
+    pub fn follow_many_unique<T, F>(self, f: F) -> impl Iterator<Item = EntityPtr>
+    where
+        T: Component,
+        F: FnOnce(&T) -> &[EntityHandle],
+    {
+        let mut seen = HashSet::new();
+        self.follow_many::<T, F>(f)
+            .filter(move |p| seen.insert(p.entity))
+    }
+
+    /// Follows the reference held by the active variant of an enum component.
+    ///
+    /// Behaves like `follow_opt`; the name signals that `f` matches on variants,
+    /// returning `None` for variants without a target.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_entity_ptr::{EntityHandle, WorldExt};
+    ///
+    /// #[derive(Component)]
+    /// enum Intent {
+    ///     Attack { target: EntityHandle },
+    ///     Idle,
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// let foe = world.spawn_empty().id();
+    /// let unit = world.spawn(Intent::Attack { target: EntityHandle::new(foe) }).id();
+    ///
+    /// let target = world.entity_ptr(unit).follow_variant::<Intent, _>(|i| match i {
+    ///     Intent::Attack { target } => Some(*target),
+    ///     Intent::Idle => None,
+    /// });
+    /// assert_eq!(target.map(|t| t.entity()), Some(foe));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn follow_variant<T, F>(self, f: F) -> Option<EntityPtr>
+    where
+        T: Component,
+        F: FnOnce(&T) -> Option<EntityHandle>,
+    {
+        self.follow_opt::<T, F>(f)
+    }
+
+    /// Follows every reference held by the active variant of an enum component.
+    ///
+    /// For variants with several targets; `f` returns them as a `Vec`, empty for
+    /// variants without targets. Returns an empty iterator if this entity doesn't
+    /// have the component.
+    #[inline]
+    pub fn follow_variants<T, F>(self, f: F) -> impl Iterator<Item = EntityPtr>
+    where
+        T: Component,
+        F: FnOnce(&T) -> Vec<EntityHandle>,
+    {
+        let world = self.world;
+        self.get::<T>()
+            .map(f)
+            .unwrap_or_default()
+            .into_iter()
+            .map(move |h| EntityPtr::new(h.entity(), world))
+    }
+
+    /// Calls `f` with every `EntityHandle` stored in component `T`.
+    ///
+    /// Does nothing if this entity doesn't have the component. See `HandleContainer`.
+    #[inline]
+    pub fn visit_handles<T: Component + HandleContainer>(self, f: impl FnMut(EntityHandle)) {
+        if let Some(c) = self.get::<T>() {
+            c.for_each_handle(f);
+        }
+    }
+
+    /// Collects every `EntityHandle` stored in component `T`, in storage order.
+    ///
+    /// Returns an empty `Vec` if this entity doesn't have the component. Calling it
+    /// for each linking component type gives an entity's full adjacency, e.g. for
+    /// exporting the entity graph as an edge list.
+    #[must_use]
+    pub fn outgoing_handles<T: Component + HandleContainer>(self) -> Vec<EntityHandle> {
+        let mut handles = Vec::new();
+        self.visit_handles::<T>(|h| handles.push(h));
+        handles
+    }
+
     /// Creates an EntityPtr from an EntityHandle using this pointer's world.
     ///
     /// Convenience method for tree traversal when you have stored handles.
@@ -232,25 +837,155 @@ impl EntityPtr {
         EntityPtr::new(handle.entity(), self.world)
     }
 
-    /// Returns a navigator for this entity, enabling `HasParent`/`HasChildren` navigation.
+    /// Borrows the child handle slice stored in `T`.
     ///
-    /// This method is always available but navigation methods require the `nav-traits` feature.
+    /// Resolves the component once and hands back the slice itself, so hot
+    /// recursive folds can iterate it directly and follow each handle with
+    /// `follow_handle()`, without going through the nav wrappers. Returns `None`
+    /// if the component is missing, unlike `nav_many().children()` which yields
+    /// nothing.
+    #[cfg(feature = "nav-traits")]
     #[inline]
-    pub const fn nav(self) -> EntityPtrNav {
-        EntityPtrNav(self)
+    #[must_use]
+    pub fn children_slice<T: Component + HasChildren>(self) -> Option<&'static [EntityHandle]> {
+        self.get::<T>().map(HasChildren::children_handles)
     }
 
-    /// Returns an iterator-like navigator for collecting multiple related entities.
+    /// Lazily walks a path generated by a step function.
     ///
-    /// Useful for iterating over children or other entity collections.
-    #[inline]
-    pub const fn nav_many(self) -> EntityPtrNavMany {
-        EntityPtrNavMany(self)
+    /// Yields this entity first, then the result of applying `step` to the last
+    /// yielded entity, until `step` returns `None`. This covers custom walks such as
+    /// linked lists or ancestor chains stored in arbitrary components.
+    ///
+    /// The walk is cycle-unaware: if the links form a cycle the iterator never ends.
+    /// Use `unfold_checked` when the data may contain cycles.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_entity_ptr::{EntityHandle, WorldExt};
+    ///
+    /// #[derive(Component)]
+    /// struct Next(Option<EntityHandle>);
+    ///
+    /// let mut world = World::new();
+    /// let tail = world.spawn(Next(None)).id();
+    /// let head = world.spawn(Next(Some(EntityHandle::new(tail)))).id();
+    ///
+    /// let ptr = world.entity_ptr(head);
+    /// let len = ptr.unfold(|p| p.follow_opt::<Next, _>(|n| n.0)).count();
+    /// assert_eq!(len, 2);
+    /// ```
+    pub fn unfold<F>(self, mut step: F) -> impl Iterator<Item = EntityPtr>
+    where
+        F: FnMut(EntityPtr) -> Option<EntityPtr>,
+    {
+        std::iter::successors(Some(self), move |&p| step(p))
+    }
+
+    /// Like `unfold`, but stops before yielding an entity a second time.
+    ///
+    /// Tracks visited entities in a `HashSet`, so cyclic links terminate.
+    pub fn unfold_checked<F>(self, step: F) -> impl Iterator<Item = EntityPtr>
+    where
+        F: FnMut(EntityPtr) -> Option<EntityPtr>,
+    {
+        let mut visited = HashSet::new();
+        self.unfold(step)
+            .take_while(move |p| visited.insert(p.entity))
+    }
+
+    /// Collects every other entity connected to this one through relationship `R`.
+    ///
+    /// Performs a breadth-first search that treats `R` as an undirected graph: each
+    /// entity's neighbours are its `R` target and every entity listed in its
+    /// `R::RelationshipTarget`. The result is this entity's whole connected
+    /// component minus the entity itself, e.g. for "select connected subgraph" in an
+    /// editor. Visited entities are tracked, so shared and cyclic links terminate.
+    ///
+    /// Allocates a visited `HashSet` sized to the connected component.
+    pub fn reachable<R: Relationship>(self) -> HashSet<Entity> {
+        let mut visited = HashSet::from([self.entity]);
+        let mut queue = VecDeque::from([self.entity]);
+        while let Some(current) = queue.pop_front() {
+            for next in relationship_neighbors::<R>(self.world, current) {
+                if visited.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        visited.remove(&self.entity);
+        visited
+    }
+
+    /// Keeps the `candidates` that are connected to this entity via relationship `R`.
+    ///
+    /// Computes `reachable::<R>()` once and returns the matching handles in their
+    /// original order, e.g. scoping an operation to one connected subgraph. Links
+    /// are followed in both directions, and this entity itself is never kept.
+    #[must_use]
+    pub fn reachable_filter<R: Relationship>(
+        self,
+        candidates: &[EntityHandle],
+    ) -> Vec<EntityHandle> {
+        let reachable = self.reachable::<R>();
+        candidates
+            .iter()
+            .copied()
+            .filter(|h| reachable.contains(&h.entity()))
+            .collect()
+    }
+
+    /// Wraps this pointer in a `GuardedPtr` that may take at most `max_depth` hops.
+    ///
+    /// A drop-in safety rail for recursive helpers: swap `EntityPtr` for
+    /// `GuardedPtr` and the recursion stops once the budget is spent, even on
+    /// cyclic data.
+    #[inline]
+    pub const fn guarded(self, max_depth: usize) -> GuardedPtr {
+        GuardedPtr::new(self, max_depth)
+    }
+
+    /// Returns a navigator for this entity, enabling `HasParent`/`HasChildren` navigation.
+    ///
+    /// This method is always available but navigation methods require the `nav-traits` feature.
+    #[inline]
+    pub const fn nav(self) -> EntityPtrNav {
+        EntityPtrNav(self)
+    }
+
+    /// Returns an iterator-like navigator for collecting multiple related entities.
+    ///
+    /// Useful for iterating over children or other entity collections.
+    #[inline]
+    pub const fn nav_many(self) -> EntityPtrNavMany {
+        EntityPtrNavMany(self)
+    }
+
+    /// Returns the address of the world this pointer reads from, as an opaque identity.
+    ///
+    /// Compare two results with `==` to assert that pointers share a world when
+    /// juggling several worlds. The pointer must **not** be dereferenced: it carries
+    /// no lifetime and may dangle once the world is dropped.
+    #[inline]
+    #[must_use]
+    pub const fn world_id(self) -> *const World {
+        std::ptr::from_ref(self.world)
+    }
+
+    /// Returns `Some(self)` if `other` reads from the same world, else `None`.
+    ///
+    /// A guard for algorithms combining pointers from different sources in
+    /// multi-world applications: `a.merge_world(b)?` bails out before mixing
+    /// worlds. Compares `world_id()`s.
+    #[inline]
+    #[must_use]
+    pub fn merge_world(self, other: EntityPtr) -> Option<EntityPtr> {
+        std::ptr::eq(self.world, other.world).then_some(self)
     }
 
-    /// Returns the world reference (used by nav module).
+    /// Returns the world reference (used by nav and debug modules).
     #[inline]
-    #[cfg_attr(not(feature = "nav-traits"), allow(dead_code))]
     pub(crate) const fn world(self) -> &'static World {
         self.world
     }
@@ -286,6 +1021,57 @@ impl std::hash::Hash for EntityPtr {
     }
 }
 
+/// An `EntityPtr` whose equality and hash include the world it reads from.
+///
+/// `EntityPtr` compares by entity ID only, so pointers into two different worlds
+/// collide as `HashMap` keys whenever their entity IDs match. Wrap them in
+/// `WorldScopedPtr` when several worlds coexist, e.g. in editor or replay tooling.
+///
+/// # Example
+/// ```
+/// use std::collections::HashSet;
+/// use bevy_ecs::prelude::*;
+/// use bevy_entity_ptr::{WorldExt, WorldScopedPtr};
+///
+/// let mut main = World::new();
+/// let mut preview = World::new();
+/// let a = main.spawn_empty().id();
+/// let b = preview.spawn_empty().id();
+/// assert_eq!(a, b);
+///
+/// let keys: HashSet<_> = [main.entity_ptr(a), preview.entity_ptr(b)]
+///     .into_iter()
+///     .map(WorldScopedPtr)
+///     .collect();
+/// assert_eq!(keys.len(), 2);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct WorldScopedPtr(pub EntityPtr);
+
+impl From<EntityPtr> for WorldScopedPtr {
+    #[inline]
+    fn from(ptr: EntityPtr) -> Self {
+        Self(ptr)
+    }
+}
+
+impl PartialEq for WorldScopedPtr {
+    /// Compares by world identity and entity ID.
+    fn eq(&self, other: &Self) -> bool {
+        self.0.world_id() == other.0.world_id() && self.0.entity == other.0.entity
+    }
+}
+
+impl Eq for WorldScopedPtr {}
+
+impl std::hash::Hash for WorldScopedPtr {
+    /// Hashes the world address together with the entity ID.
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.world_id().hash(state);
+        self.0.entity.hash(state);
+    }
+}
+
 // Explicitly NOT implementing Send or Sync - EntityPtr must stay on creating thread
 
 /// Navigation wrapper for `EntityPtr`, providing parent/children traversal.
@@ -328,6 +1114,131 @@ impl std::fmt::Debug for EntityPtrNavMany {
     }
 }
 
+/// Sorts pointers in place by a key computed from component `T`.
+///
+/// Entities missing `T` (or despawned) are placed at the end. The sort is stable,
+/// so ties and the trailing missing entities keep their original order. `T` is
+/// looked up and `key` called once per pointer, not once per comparison.
+///
+/// # Example
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_entity_ptr::{WorldExt, sort_by_component};
+///
+/// #[derive(Component)]
+/// struct Health(i32);
+///
+/// let mut world = World::new();
+/// let ids = [world.spawn(Health(30)).id(), world.spawn_empty().id(), world.spawn(Health(10)).id()];
+/// let mut ptrs: Vec<_> = ids.iter().map(|&e| world.entity_ptr(e)).collect();
+///
+/// sort_by_component::<Health, _, _>(&mut ptrs, |h| h.0);
+/// assert_eq!(ptrs.iter().map(|p| p.entity()).collect::<Vec<_>>(), [ids[2], ids[0], ids[1]]);
+/// ```
+pub fn sort_by_component<T, K, F>(ptrs: &mut [EntityPtr], mut key: F)
+where
+    T: Component,
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    // Each key is computed once; `true` sorts after `false`, so missing entities go last.
+    ptrs.sort_by_cached_key(|p| {
+        let k = p.get::<T>().map(&mut key);
+        (k.is_none(), k)
+    });
+}
+
+/// Returns the fewest relationship `R` links separating `from` and `to`.
+///
+/// Performs the same undirected breadth-first search as `EntityPtr::reachable()`,
+/// so this returns `Some` exactly when `to` is `from` itself (`Some(0)`) or in
+/// `from.reachable::<R>()`. This gives distances on arbitrary relationship graphs,
+/// e.g. two siblings under `ChildOf` are 2 hops apart. Returns `None` if `to` is
+/// unreachable. Visited entities are tracked, so cycles terminate.
+///
+/// # Example
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_entity_ptr::{WorldExt, shortest_hops};
+///
+/// let mut world = World::new();
+/// let root = world.spawn_empty().id();
+/// let a = world.spawn(ChildOf(root)).id();
+/// let b = world.spawn(ChildOf(root)).id();
+/// let leaf = world.spawn(ChildOf(b)).id();
+/// let loner = world.spawn_empty().id();
+///
+/// assert_eq!(shortest_hops::<ChildOf>(world.entity_ptr(a), b), Some(2));
+/// assert_eq!(shortest_hops::<ChildOf>(world.entity_ptr(root), leaf), Some(2));
+/// assert_eq!(shortest_hops::<ChildOf>(world.entity_ptr(a), loner), None);
+/// ```
+pub fn shortest_hops<R: Relationship>(from: EntityPtr, to: Entity) -> Option<usize> {
+    let world = from.world;
+    let mut visited = HashSet::from([from.entity]);
+    let mut queue = VecDeque::from([(from.entity, 0)]);
+    while let Some((current, hops)) = queue.pop_front() {
+        if current == to {
+            return Some(hops);
+        }
+        for next in relationship_neighbors::<R>(world, current) {
+            if visited.insert(next) {
+                queue.push_back((next, hops + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Returns the entities one `R` link away from `entity`, in either direction.
+///
+/// Yields its `R` target, then every entity listed in its `R::RelationshipTarget`.
+fn relationship_neighbors<R: Relationship>(
+    world: &World,
+    entity: Entity,
+) -> impl Iterator<Item = Entity> + '_ {
+    let outgoing = world.get::<R>(entity).map(Relationship::get);
+    let incoming = world
+        .get::<R::RelationshipTarget>(entity)
+        .into_iter()
+        .flat_map(RelationshipTarget::iter);
+    outgoing.into_iter().chain(incoming)
+}
+
+/// Filtering adapters for iterators over `EntityPtr`.
+///
+/// Traversals can yield pointers to entities that have since been despawned or
+/// that lack the data a system cares about. These adapters keep such chains
+/// readable, e.g. `ptr.follow_many::<Team, _>(|t| &t.0).alive().with_component::<Health>()`.
+///
+/// # Example
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_entity_ptr::{EntityPtrIterExt, WorldExt};
+///
+/// #[derive(Component)]
+/// struct Health(i32);
+///
+/// let mut world = World::new();
+/// let ids = [world.spawn(Health(5)).id(), world.spawn_empty().id()];
+/// let ptrs: Vec<_> = ids.iter().map(|&e| world.entity_ptr(e)).collect();
+///
+/// let healthy: Vec<_> = ptrs.into_iter().with_component::<Health>().collect();
+/// assert_eq!(healthy.len(), 1);
+/// ```
+pub trait EntityPtrIterExt: Iterator<Item = EntityPtr> + Sized {
+    /// Skips pointers whose entities have been despawned.
+    fn alive(self) -> impl Iterator<Item = EntityPtr> {
+        self.filter(|p| p.is_alive())
+    }
+
+    /// Keeps only pointers whose entities have component `C`.
+    fn with_component<C: Component>(self) -> impl Iterator<Item = EntityPtr> {
+        self.filter(|p| p.has::<C>())
+    }
+}
+
+impl<I: Iterator<Item = EntityPtr>> EntityPtrIterExt for I {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -335,6 +1246,12 @@ mod tests {
     #[derive(Component)]
     struct Name(&'static str);
 
+    impl Named for Name {
+        fn name(&self) -> &str {
+            self.0
+        }
+    }
+
     #[derive(Component)]
     struct Health(i32);
 
@@ -344,6 +1261,53 @@ mod tests {
     #[derive(Component)]
     struct OptionalTarget(Option<EntityHandle>);
 
+    #[derive(Component)]
+    struct Team(Vec<EntityHandle>);
+
+    #[derive(Component)]
+    struct LinkedList(Option<EntityHandle>);
+
+    #[derive(Component)]
+    struct Links {
+        primary: EntityHandle,
+        backup: Option<EntityHandle>,
+        extra: Vec<EntityHandle>,
+    }
+
+    impl HandleContainer for Links {
+        fn for_each_handle(&self, mut f: impl FnMut(EntityHandle)) {
+            self.primary.for_each_handle(&mut f);
+            self.backup.for_each_handle(&mut f);
+            self.extra.for_each_handle(f);
+        }
+    }
+
+    impl HandleContainer for Target {
+        fn for_each_handle(&self, f: impl FnMut(EntityHandle)) {
+            self.0.for_each_handle(f);
+        }
+    }
+
+    impl HandleContainer for OptionalTarget {
+        fn for_each_handle(&self, f: impl FnMut(EntityHandle)) {
+            self.0.for_each_handle(f);
+        }
+    }
+
+    impl HandleContainer for Team {
+        fn for_each_handle(&self, f: impl FnMut(EntityHandle)) {
+            self.0.for_each_handle(f);
+        }
+    }
+
+    #[derive(Component)]
+    #[relationship(relationship_target = LinkedFrom)]
+    struct LinksTo(Entity);
+
+    #[derive(Component)]
+    #[relationship_target(relationship = LinksTo)]
+    struct LinkedFrom(Vec<Entity>);
+
     #[test]
     fn worldref_is_copy() {
         let mut world = World::new();
@@ -407,6 +1371,31 @@ mod tests {
         assert_eq!(world_ref.get::<Name>(entity).unwrap().0, "direct");
     }
 
+    #[test]
+    fn worldref_get_pair() {
+        let mut world = World::new();
+        let attacker = world.spawn((Name("attacker"), Health(90))).id();
+        let defender = world.spawn((Name("defender"), Health(40))).id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+
+        let (name, health) = world_ref.get_pair::<Name, Health>(attacker, defender);
+        assert_eq!(name.unwrap().0, "attacker");
+        assert_eq!(health.unwrap().0, 40);
+
+        // Same entity on both sides is fine for reads
+        let (name, health) = world_ref.get_pair::<Name, Health>(defender, defender);
+        assert_eq!((name.unwrap().0, health.unwrap().0), ("defender", 40));
+
+        let (a, b) = world_ref.get_pair_same::<Health>(attacker, defender);
+        assert_eq!((a.unwrap().0, b.unwrap().0), (90, 40));
+
+        let (target, health) = world_ref.get_pair::<Target, Health>(attacker, attacker);
+        assert!(target.is_none());
+        assert!(health.is_some());
+    }
+
     #[test]
     fn entityptr_handle_conversion() {
         let mut world = World::new();
@@ -420,6 +1409,68 @@ mod tests {
         assert_eq!(ptr.handle().entity(), entity);
     }
 
+    #[test]
+    fn entityptr_get_and_world() {
+        let mut world = World::new();
+        let hero = world.spawn((Name("hero"), Health(10))).id();
+        let plain = world.spawn_empty().id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let (health, raw) = world_ref.entity(hero).get_and_world::<Health>().unwrap();
+        assert_eq!(health.0, 10);
+        assert!(std::ptr::eq(raw, &world));
+        assert_eq!(raw.get::<Name>(hero).unwrap().0, "hero");
+        assert!(world_ref.entity(plain).get_and_world::<Health>().is_none());
+    }
+
+    #[test]
+    fn entityptr_zip_get() {
+        let mut world = World::new();
+        let attacker = world.spawn(Health(80)).id();
+        let target = world.spawn(Health(30)).id();
+        let bystander = world.spawn(Name("bystander")).id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+
+        let (a, t) = world_ref
+            .entity(attacker)
+            .zip_get::<Health>(world_ref.entity(target))
+            .unwrap();
+        assert!(a.0 > t.0);
+        assert!(
+            world_ref
+                .entity(attacker)
+                .zip_get::<Health>(world_ref.entity(bystander))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn entityptr_get_slice() {
+        use bevy_ecs::hierarchy::{ChildOf, Children};
+
+        let mut world = World::new();
+        let parent = world.spawn_empty().id();
+        let a = world.spawn(ChildOf(parent)).id();
+        let b = world.spawn(ChildOf(parent)).id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let kids = world_ref
+            .entity(parent)
+            .get_slice::<Children, _, _>(|c| c)
+            .unwrap();
+        assert_eq!(kids, [a, b]);
+        assert!(
+            world_ref
+                .entity(a)
+                .get_slice::<Children, _, _>(|c| c)
+                .is_none()
+        );
+    }
+
     #[test]
     fn entityptr_follow() {
         let mut world = World::new();
@@ -437,6 +1488,199 @@ mod tests {
         assert_eq!(target.get::<Name>().unwrap().0, "target");
     }
 
+    #[test]
+    fn entityptr_name_via() {
+        let mut world = World::new();
+        let target = world.spawn(Name("bob")).id();
+        let source = world.spawn(Target(EntityHandle::new(target))).id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let ptr = world_ref.entity(source);
+
+        assert_eq!(ptr.name_via::<Name>(), None);
+        assert_eq!(
+            ptr.follow::<Target, _>(|t| t.0).unwrap().name_via::<Name>(),
+            Some("bob")
+        );
+    }
+
+    #[derive(Component, Clone, Copy)]
+    struct Guid(u64);
+
+    impl From<Guid> for u64 {
+        fn from(id: Guid) -> u64 {
+            id.0
+        }
+    }
+
+    #[test]
+    fn entityptr_stable_id() {
+        let mut world = World::new();
+        let saved = world.spawn((Name("saved"), Guid(0xDEAD_BEEF))).id();
+        let transient = world.spawn(Name("transient")).id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        assert_eq!(
+            world_ref.entity(saved).stable_id::<Guid>(),
+            Some(0xDEAD_BEEF)
+        );
+        assert_eq!(world_ref.entity(transient).stable_id::<Guid>(), None);
+
+        let bound = EntityHandle::new(saved).bind(&world);
+        assert_eq!(bound.stable_id::<Guid>(), Some(0xDEAD_BEEF));
+    }
+
+    #[test]
+    fn entityptr_follow_get() {
+        let mut world = World::new();
+        let target = world.spawn(Health(40)).id();
+        let unhealthy = world.spawn(Name("no health")).id();
+        let attacker = world
+            .spawn((Name("attacker"), Target(EntityHandle::new(target))))
+            .id();
+        let confused = world.spawn(Target(EntityHandle::new(unhealthy))).id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let ptr = world_ref.entity(attacker);
+        let (link, health) = ptr.follow_get::<Target, Health, _>(|t| t.0).unwrap();
+        assert_eq!(link.0.entity(), target);
+        assert_eq!(health.0, 40);
+        assert_eq!(ptr.get::<Name>().unwrap().0, "attacker");
+
+        // Target lacks the requested component
+        assert!(
+            world_ref
+                .entity(confused)
+                .follow_get::<Target, Health, _>(|t| t.0)
+                .is_none()
+        );
+        // Source lacks the link component
+        assert!(
+            world_ref
+                .entity(target)
+                .follow_get::<Target, Health, _>(|t| t.0)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn entityptr_follow_variant() {
+        #[derive(Component)]
+        enum Order {
+            Attack {
+                target: EntityHandle,
+            },
+            Escort {
+                leader: EntityHandle,
+                ward: EntityHandle,
+            },
+            Idle,
+        }
+
+        fn primary(order: &Order) -> Option<EntityHandle> {
+            match order {
+                Order::Attack { target } => Some(*target),
+                Order::Escort { leader, .. } => Some(*leader),
+                Order::Idle => None,
+            }
+        }
+
+        fn all_targets(order: &Order) -> Vec<EntityHandle> {
+            match order {
+                Order::Attack { target } => vec![*target],
+                Order::Escort { leader, ward } => vec![*leader, *ward],
+                Order::Idle => Vec::new(),
+            }
+        }
+
+        let mut world = World::new();
+        let foe = world.spawn(Name("foe")).id();
+        let leader = world.spawn(Name("leader")).id();
+        let ward = world.spawn(Name("ward")).id();
+        let attacker = world
+            .spawn(Order::Attack {
+                target: EntityHandle::new(foe),
+            })
+            .id();
+        let escort = world
+            .spawn(Order::Escort {
+                leader: EntityHandle::new(leader),
+                ward: EntityHandle::new(ward),
+            })
+            .id();
+        let idle = world.spawn(Order::Idle).id();
+        let no_orders = world.spawn(Name("civilian")).id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let follow = |e| {
+            world_ref
+                .entity(e)
+                .follow_variant::<Order, _>(primary)
+                .map(|p| p.entity())
+        };
+        assert_eq!(follow(attacker), Some(foe));
+        assert_eq!(follow(escort), Some(leader));
+        assert_eq!(follow(idle), None);
+        assert_eq!(follow(no_orders), None);
+
+        let escorted: Vec<_> = world_ref
+            .entity(escort)
+            .follow_variants::<Order, _>(all_targets)
+            .map(|p| p.get::<Name>().unwrap().0)
+            .collect();
+        assert_eq!(escorted, vec!["leader", "ward"]);
+        assert_eq!(
+            world_ref
+                .entity(idle)
+                .follow_variants::<Order, _>(all_targets)
+                .count(),
+            0
+        );
+        assert_eq!(
+            world_ref
+                .entity(no_orders)
+                .follow_variants::<Order, _>(all_targets)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn entityptr_follow_typed() {
+        #[derive(Component)]
+        struct Weapon(u32);
+
+        #[derive(Component)]
+        struct Wields(TypedEntityHandle<Weapon>);
+
+        let mut world = World::new();
+        let sword = world.spawn(Weapon(12)).id();
+        let rock = world.spawn(Name("rock")).id();
+        let knight = world.spawn(Wields(TypedEntityHandle::new(sword))).id();
+        let fool = world.spawn(Wields(TypedEntityHandle::new(rock))).id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+
+        let weapon = world_ref
+            .entity(knight)
+            .follow_typed::<Wields, Weapon, _>(|w| w.0)
+            .unwrap();
+        assert_eq!(weapon.get::<Weapon>().unwrap().0, 12);
+
+        // Target lacks the tagged component
+        assert!(
+            world_ref
+                .entity(fool)
+                .follow_typed::<Wields, Weapon, _>(|w| w.0)
+                .is_none()
+        );
+    }
+
     #[test]
     fn entityptr_follow_opt() {
         let mut world = World::new();
@@ -458,48 +1702,1022 @@ mod tests {
     }
 
     #[test]
-    fn entityptr_follow_handle() {
+    fn entityptr_follow_opt_or() {
         let mut world = World::new();
+        let fallback = world.spawn(Name("fallback")).id();
         let target_entity = world.spawn(Name("target")).id();
-        let source_entity = world.spawn(Name("source")).id();
+        let with_target = world
+            .spawn(OptionalTarget(Some(EntityHandle::new(target_entity))))
+            .id();
+        let without_target = world.spawn(OptionalTarget(None)).id();
+        let plain = world.spawn(Name("plain")).id();
 
         // SAFETY: world outlives the WorldRef usage in this test
         let world_ref = unsafe { WorldRef::new(&world) };
+        let follow = |e| {
+            world_ref
+                .entity(e)
+                .follow_opt_or::<OptionalTarget, _>(|t| t.0, fallback)
+                .entity()
+        };
+        assert_eq!(follow(with_target), target_entity);
+        assert_eq!(follow(without_target), fallback);
+        assert_eq!(follow(plain), fallback);
+    }
 
-        let source = world_ref.entity(source_entity);
-        let handle = EntityHandle::new(target_entity);
+    #[test]
+    fn entityptr_follow_res() {
+        let mut world = World::new();
+        let target_entity = world.spawn(Name("target")).id();
+        let source_entity = world
+            .spawn((
+                Target(EntityHandle::new(target_entity)),
+                OptionalTarget(None),
+            ))
+            .id();
+        let plain = world.spawn(Name("plain")).id();
+        let gone = world.spawn(Name("gone")).id();
+        world.despawn(gone);
 
-        // follow_handle lets us convert a handle to an EntityPtr using source's world
-        let target = source.follow_handle(handle);
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
 
+        let source = world_ref.entity(source_entity);
+        let target = source.follow_res::<Target, _>(|t| t.0).unwrap();
         assert_eq!(target.entity(), target_entity);
-        assert_eq!(target.get::<Name>().unwrap().0, "target");
-    }
 
-    // Note: Stale reference tests for EntityPtr are NOT valid because
-    // mutating the World while holding EntityPtrs is undefined behavior.
-    // Use EntityHandle for stale reference handling across mutations.
-    // See handle::tests::handle_stale_entity and integration_tests::stale_reference_handling.
+        let err = world_ref
+            .entity(plain)
+            .follow_res::<Target, _>(|t| t.0)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            EntityAccessError::ComponentMissing { entity, component }
+                if entity == plain && component.ends_with("Target")
+        ));
+
+        let err = source
+            .follow_opt_res::<OptionalTarget, _>(|t| t.0)
+            .unwrap_err();
+        assert!(
+            matches!(err, EntityAccessError::LinkEmpty { entity, .. } if entity == source_entity)
+        );
+
+        let err = world_ref
+            .entity(gone)
+            .follow_opt_res::<OptionalTarget, _>(|t| t.0)
+            .unwrap_err();
+        assert_eq!(err, EntityAccessError::Despawned { entity: gone });
+        assert_eq!(err.entity(), gone);
+        assert!(err.to_string().contains("despawned"));
+    }
 
     #[test]
-    fn memory_layout() {
-        assert_eq!(std::mem::size_of::<WorldRef>(), 8);
-        assert_eq!(std::mem::size_of::<EntityPtr>(), 16);
+    fn entityptr_follow_res_with_question_mark() {
+        fn target_name(ptr: EntityPtr) -> Result<&'static str, EntityAccessError> {
+            let target = ptr.follow_res::<Target, _>(|t| t.0)?;
+            Ok(target.get::<Name>().map_or("unnamed", |n| n.0))
+        }
+
+        let mut world = World::new();
+        let target = world.spawn(Name("target")).id();
+        let source = world.spawn(Target(EntityHandle::new(target))).id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+
+        assert_eq!(target_name(world_ref.entity(source)), Ok("target"));
+        assert!(target_name(world_ref.entity(target)).is_err());
     }
 
     #[test]
-    fn nav_wrapper_access() {
+    fn entityptr_follow_many() {
         let mut world = World::new();
-        let entity = world.spawn(Name("nav")).id();
+        let alice = world.spawn(Name("alice")).id();
+        let bob = world.spawn(Name("bob")).id();
+        let team = world
+            .spawn(Team(vec![EntityHandle::new(alice), EntityHandle::new(bob)]))
+            .id();
 
         // SAFETY: world outlives the WorldRef usage in this test
         let world_ref = unsafe { WorldRef::new(&world) };
 
-        let ptr = world_ref.entity(entity);
+        let members: Vec<_> = world_ref
+            .entity(team)
+            .follow_many::<Team, _>(|t| &t.0)
+            .collect();
+        assert_eq!(members.len(), 2);
+        assert_eq!(members[0].get::<Name>().unwrap().0, "alice");
+        assert_eq!(members[1].get::<Name>().unwrap().0, "bob");
+
+        let without = world_ref.entity(alice);
+        assert_eq!(without.follow_many::<Team, _>(|t| &t.0).count(), 0);
+    }
+
+    #[test]
+    fn entityptr_follow_many_unique() {
+        let mut world = World::new();
+        let alice = world.spawn(Name("alice")).id();
+        let bob = world.spawn(Name("bob")).id();
+        let team = world
+            .spawn(Team(
+                [bob, alice, bob, bob, alice]
+                    .map(EntityHandle::new)
+                    .to_vec(),
+            ))
+            .id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let unique: Vec<_> = world_ref
+            .entity(team)
+            .follow_many_unique::<Team, _>(|t| &t.0)
+            .map(|p| p.entity())
+            .collect();
+        assert_eq!(unique, vec![bob, alice]);
+        assert_eq!(
+            world_ref
+                .entity(alice)
+                .follow_many_unique::<Team, _>(|t| &t.0)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn entityptr_unfold_linked_list() {
+        let mut world = World::new();
+        let c = world.spawn((Name("c"), LinkedList(None))).id();
+        let b = world
+            .spawn((Name("b"), LinkedList(Some(EntityHandle::new(c)))))
+            .id();
+        let a = world
+            .spawn((Name("a"), LinkedList(Some(EntityHandle::new(b)))))
+            .id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+
+        let names: Vec<_> = world_ref
+            .entity(a)
+            .unfold(|p| p.follow_opt::<LinkedList, _>(|l| l.0))
+            .map(|p| p.get::<Name>().unwrap().0)
+            .collect();
+        assert_eq!(names, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn entityptr_unfold_checked_cycle() {
+        let mut world = World::new();
+        let a = world.spawn(Name("a")).id();
+        let b = world
+            .spawn((Name("b"), LinkedList(Some(EntityHandle::new(a)))))
+            .id();
+        world
+            .entity_mut(a)
+            .insert(LinkedList(Some(EntityHandle::new(b))));
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let step = |p: EntityPtr| p.follow_opt::<LinkedList, _>(|l| l.0);
+
+        // Unchecked keeps cycling
+        assert_eq!(world_ref.entity(a).unfold(step).take(5).count(), 5);
+
+        let walked: Vec<_> = world_ref
+            .entity(a)
+            .unfold_checked(step)
+            .map(|p| p.entity())
+            .collect();
+        assert_eq!(walked, vec![a, b]);
+    }
+
+    #[test]
+    fn entityptr_reachable_chain() {
+        let mut world = World::new();
+        let c = world.spawn(Name("c")).id();
+        let b = world.spawn((Name("b"), LinksTo(c))).id();
+        let a = world.spawn((Name("a"), LinksTo(b))).id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+
+        assert_eq!(
+            world_ref.entity(a).reachable::<LinksTo>(),
+            HashSet::from([b, c])
+        );
+        // Back-links are followed too, so the whole chain is one component
+        assert_eq!(
+            world_ref.entity(c).reachable::<LinksTo>(),
+            HashSet::from([a, b])
+        );
+    }
+
+    #[test]
+    fn entityptr_reachable_from_hub() {
+        let mut world = World::new();
+        // a -> hub <- b <- d: the hub only has incoming links
+        let hub = world.spawn(Name("hub")).id();
+        let a = world.spawn((Name("a"), LinksTo(hub))).id();
+        let b = world.spawn((Name("b"), LinksTo(hub))).id();
+        let d = world.spawn((Name("d"), LinksTo(b))).id();
+        let island = world.spawn(Name("island")).id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+
+        assert_eq!(
+            world_ref.entity(hub).reachable::<LinksTo>(),
+            HashSet::from([a, b, d])
+        );
+        assert!(world_ref.entity(island).reachable::<LinksTo>().is_empty());
+    }
+
+    #[test]
+    fn entityptr_reachable_cycle_terminates() {
+        let mut world = World::new();
+        let a = world.spawn(Name("a")).id();
+        let b = world.spawn((Name("b"), LinksTo(a))).id();
+        let c = world.spawn((Name("c"), LinksTo(b))).id();
+        world.entity_mut(a).insert(LinksTo(c));
+        let outsider = world.spawn(Name("outsider")).id();
+
+        // d hangs off the cycle, so it is only found through a back-link
+        let d = world.spawn((Name("d"), LinksTo(b))).id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+
+        let reachable = world_ref.entity(a).reachable::<LinksTo>();
+        assert_eq!(reachable, HashSet::from([b, c, d]));
+        assert!(!reachable.contains(&outsider));
+    }
+
+    #[test]
+    fn entityptr_reachable_filter() {
+        let mut world = World::new();
+        // Component one: a -> hub <- b <- c. Component two: x -> y.
+        let hub = world.spawn(Name("hub")).id();
+        let a = world.spawn((Name("a"), LinksTo(hub))).id();
+        let b = world.spawn((Name("b"), LinksTo(hub))).id();
+        let c = world.spawn((Name("c"), LinksTo(b))).id();
+        let y = world.spawn(Name("y")).id();
+        let x = world.spawn((Name("x"), LinksTo(y))).id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let candidates = [x, c, a, y, hub, b].map(EntityHandle::new);
+
+        // Every node of a component scopes to the same subgraph, minus itself
+        assert_eq!(
+            world_ref.entity(a).reachable_filter::<LinksTo>(&candidates),
+            [c, hub, b].map(EntityHandle::new).to_vec()
+        );
+        assert_eq!(
+            world_ref.entity(c).reachable_filter::<LinksTo>(&candidates),
+            [a, hub, b].map(EntityHandle::new).to_vec()
+        );
+        assert_eq!(
+            world_ref.entity(y).reachable_filter::<LinksTo>(&candidates),
+            vec![EntityHandle::new(x)]
+        );
+        assert!(
+            world_ref
+                .entity(a)
+                .reachable_filter::<LinksTo>(&[EntityHandle::new(x)])
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn world_scoped_ptr_keeps_worlds_apart() {
+        let mut main = World::new();
+        let mut other = World::new();
+        let a = main.spawn(Health(1)).id();
+        let b = other.spawn(Health(2)).id();
+        assert_eq!(a, b);
+
+        // SAFETY: both worlds outlive the WorldRef usage in this test
+        let (main_ref, other_ref) = unsafe { (WorldRef::new(&main), WorldRef::new(&other)) };
+        let (pa, pb) = (main_ref.entity(a), other_ref.entity(b));
+
+        // Plain pointers collide across worlds
+        assert_eq!(HashSet::from([pa, pb]).len(), 1);
+
+        #[allow(clippy::mutable_key_type)]
+        let mut map = std::collections::HashMap::new();
+        map.insert(WorldScopedPtr(pa), "main");
+        map.insert(WorldScopedPtr::from(pb), "other");
+        assert_eq!(map.len(), 2);
+        assert_eq!(map[&WorldScopedPtr(main_ref.entity(a))], "main");
+        assert_eq!(map[&WorldScopedPtr(other_ref.entity(b))], "other");
+    }
+
+    #[test]
+    fn shortest_hops_on_graph() {
+        let mut world = World::new();
+        // a -> hub <- b <- d <- c: distances ignore link direction
+        let hub = world.spawn(Name("hub")).id();
+        let a = world.spawn((Name("a"), LinksTo(hub))).id();
+        let b = world.spawn((Name("b"), LinksTo(hub))).id();
+        let c = world.spawn(Name("c")).id();
+        let d = world.spawn((Name("d"), LinksTo(b))).id();
+        world.entity_mut(c).insert(LinksTo(d));
+        let island = world.spawn(Name("island")).id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+
+        assert_eq!(shortest_hops::<LinksTo>(world_ref.entity(a), a), Some(0));
+        assert_eq!(shortest_hops::<LinksTo>(world_ref.entity(a), hub), Some(1));
+        assert_eq!(shortest_hops::<LinksTo>(world_ref.entity(a), b), Some(2));
+        assert_eq!(shortest_hops::<LinksTo>(world_ref.entity(a), d), Some(3));
+        assert_eq!(shortest_hops::<LinksTo>(world_ref.entity(a), c), Some(4));
+        assert_eq!(shortest_hops::<LinksTo>(world_ref.entity(c), a), Some(4));
+        assert_eq!(shortest_hops::<LinksTo>(world_ref.entity(a), island), None);
+
+        // Agrees with `reachable` for every pair
+        let all = [hub, a, b, c, d, island];
+        for &x in &all {
+            let from = world_ref.entity(x);
+            let reachable = from.reachable::<LinksTo>();
+            for &y in &all {
+                assert_eq!(
+                    shortest_hops::<LinksTo>(from, y).is_some(),
+                    x == y || reachable.contains(&y)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn entityptr_visit_handles() {
+        let mut world = World::new();
+        let [a, b, c] = [(); 3].map(|_| world.spawn_empty().id());
+        let source = world
+            .spawn(Links {
+                primary: EntityHandle::new(a),
+                backup: None,
+                extra: vec![EntityHandle::new(b), EntityHandle::new(c)],
+            })
+            .id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+
+        let mut seen = Vec::new();
+        world_ref
+            .entity(source)
+            .visit_handles::<Links>(|h| seen.push(h.entity()));
+        assert_eq!(seen, vec![a, b, c]);
+
+        let mut calls = 0;
+        world_ref.entity(a).visit_handles::<Links>(|_| calls += 1);
+        assert_eq!(calls, 0);
+    }
+
+    #[test]
+    fn entityptr_outgoing_handles() {
+        let mut world = World::new();
+        let [a, b, c] = [(); 3].map(|_| world.spawn_empty().id());
+        let [ha, hb, hc] = [a, b, c].map(EntityHandle::new);
+        let source = world
+            .spawn((Target(ha), OptionalTarget(Some(hb)), Team(vec![hc, ha])))
+            .id();
+        let unset = world.spawn((OptionalTarget(None), Team(Vec::new()))).id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let ptr = world_ref.entity(source);
+        assert_eq!(ptr.outgoing_handles::<Target>(), vec![ha]);
+        assert_eq!(ptr.outgoing_handles::<OptionalTarget>(), vec![hb]);
+        assert_eq!(ptr.outgoing_handles::<Team>(), vec![hc, ha]);
+
+        let unset = world_ref.entity(unset);
+        assert!(unset.outgoing_handles::<OptionalTarget>().is_empty());
+        assert!(unset.outgoing_handles::<Team>().is_empty());
+        // Missing component
+        assert!(unset.outgoing_handles::<Target>().is_empty());
+    }
+
+    #[test]
+    fn entityptr_follow_handle() {
+        let mut world = World::new();
+        let target_entity = world.spawn(Name("target")).id();
+        let source_entity = world.spawn(Name("source")).id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+
+        let source = world_ref.entity(source_entity);
+        let handle = EntityHandle::new(target_entity);
+
+        // follow_handle lets us convert a handle to an EntityPtr using source's world
+        let target = source.follow_handle(handle);
+
+        assert_eq!(target.entity(), target_entity);
+        assert_eq!(target.get::<Name>().unwrap().0, "target");
+    }
+
+    // Note: Stale reference tests for EntityPtr are NOT valid because
+    // mutating the World while holding EntityPtrs is undefined behavior.
+    // Use EntityHandle for stale reference handling across mutations.
+    // See handle::tests::handle_stale_entity and integration_tests::stale_reference_handling.
+
+    #[test]
+    fn memory_layout() {
+        assert_eq!(std::mem::size_of::<WorldRef>(), 8);
+        assert_eq!(std::mem::size_of::<EntityPtr>(), 16);
+    }
+
+    #[test]
+    fn nav_wrapper_access() {
+        let mut world = World::new();
+        let entity = world.spawn(Name("nav")).id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+
+        let ptr = world_ref.entity(entity);
         let nav = ptr.nav();
         let nav_many = ptr.nav_many();
 
         assert_eq!(nav.inner().entity(), entity);
         assert_eq!(nav_many.inner().entity(), entity);
     }
+
+    #[test]
+    fn entityptr_and_then_if_alive() {
+        let mut world = World::new();
+        let target = world.spawn(Health(5)).id();
+        let source = world.spawn(Target(EntityHandle::new(target))).id();
+        world.despawn(target);
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let followed = world_ref
+            .entity(source)
+            .follow::<Target, _>(|t| t.0)
+            .unwrap();
+        let mut calls = 0;
+        let result = followed.and_then_if_alive(|p| {
+            calls += 1;
+            Some(p.entity())
+        });
+        assert_eq!(result, None);
+        assert_eq!(calls, 0);
+
+        let source = world_ref.entity(source);
+        assert_eq!(
+            source.and_then_if_alive(|p| p.get::<Target>().map(|t| t.0)),
+            Some(EntityHandle::new(target))
+        );
+        assert!(source.and_then_if_alive(|p| p.get::<Health>()).is_none());
+    }
+
+    #[test]
+    fn entityptr_location() {
+        let mut world = World::new();
+        let a = world.spawn(Name("a")).id();
+        let b = world.spawn(Name("b")).id();
+        let c = world.spawn(Health(1)).id();
+        let gone = world.spawn(Name("gone")).id();
+        world.despawn(gone);
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let loc_a = world_ref.entity(a).location().unwrap();
+        let loc_b = world_ref.entity(b).location().unwrap();
+        let loc_c = world_ref.entity(c).location().unwrap();
+
+        assert_eq!(loc_a.archetype_id, loc_b.archetype_id);
+        assert_ne!(loc_a.archetype_id, loc_c.archetype_id);
+        assert_ne!(loc_a.table_row, loc_b.table_row);
+        assert!(world_ref.entity(gone).location().is_none());
+    }
+
+    #[test]
+    fn entityptr_same_archetype() {
+        let mut world = World::new();
+        let a = world.spawn((Name("a"), Health(1))).id();
+        let b = world.spawn((Health(2), Name("b"))).id();
+        let c = world.spawn(Name("c")).id();
+        let gone = world.spawn((Name("gone"), Health(3))).id();
+        world.despawn(gone);
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let [a, b, c, gone] = [a, b, c, gone].map(|e| world_ref.entity(e));
+        assert!(a.same_archetype(b));
+        assert!(a.same_archetype(a));
+        assert!(!a.same_archetype(c));
+        assert!(!a.same_archetype(gone));
+        assert!(!gone.same_archetype(gone));
+
+        // The first archetype spawned in another world can share `a`'s archetype ID
+        let mut other = World::new();
+        let foreign = other.spawn(Health(4)).id();
+        // SAFETY: other outlives the WorldRef usage in this test
+        let foreign = unsafe { WorldRef::new(&other) }.entity(foreign);
+        assert!(!a.same_archetype(foreign));
+        assert!(!foreign.same_archetype(a));
+    }
+
+    #[test]
+    fn entityptr_expect_alive_passes_through() {
+        let mut world = World::new();
+        let entity = world.spawn(Health(3)).id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let ptr = world_ref.entity(entity).expect_alive("spawned above");
+        assert_eq!(ptr.get::<Health>().unwrap().0, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "target setup: entity")]
+    fn entityptr_expect_alive_panics_when_despawned() {
+        let mut world = World::new();
+        let entity = world.spawn(Health(3)).id();
+        world.despawn(entity);
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let _ = world_ref.entity(entity).expect_alive("target setup");
+    }
+
+    #[test]
+    fn entityptr_component_ids_filtered() {
+        let mut world = World::new();
+        let entity = world
+            .spawn((
+                Name("a"),
+                Health(1),
+                Target(EntityHandle::new(Entity::PLACEHOLDER)),
+            ))
+            .id();
+        let gone = world.spawn(Name("gone")).id();
+        world.despawn(gone);
+        let name_id = world.component_id::<Name>().unwrap();
+        let health_id = world.component_id::<Health>().unwrap();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let pinned = [name_id, health_id];
+        let mut ids = world_ref
+            .entity(entity)
+            .component_ids_filtered(|id| pinned.contains(&id));
+        ids.sort();
+        let mut expected = pinned.to_vec();
+        expected.sort();
+        assert_eq!(ids, expected);
+        assert_eq!(
+            world_ref
+                .entity(entity)
+                .component_ids_filtered(|_| true)
+                .len(),
+            3
+        );
+        assert!(
+            world_ref
+                .entity(gone)
+                .component_ids_filtered(|_| true)
+                .is_empty()
+        );
+
+        let bound = EntityHandle::new(entity).bind(&world);
+        assert_eq!(
+            bound.component_ids_filtered(|id| id == name_id),
+            vec![name_id]
+        );
+    }
+
+    #[test]
+    fn entityptr_component_count() {
+        let mut world = World::new();
+        let empty = world.spawn_empty().id();
+        let two = world.spawn((Name("a"), Health(1))).id();
+        let gone = world.spawn(Name("gone")).id();
+        world.despawn(gone);
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        assert_eq!(world_ref.entity(empty).component_count(), 0);
+        assert_eq!(world_ref.entity(two).component_count(), 2);
+        assert_eq!(world_ref.entity(gone).component_count(), 0);
+    }
+
+    #[test]
+    fn worldref_entity_count() {
+        let mut world = World::new();
+        {
+            // SAFETY: world outlives the WorldRef usage in this block
+            let world_ref = unsafe { WorldRef::new(&world) };
+            assert!(world_ref.is_empty());
+            assert_eq!(world_ref.entity_count(), 0);
+        }
+
+        let a = world.spawn(Name("a")).id();
+        world.spawn(Name("b"));
+        world.spawn(Health(3));
+        world.despawn(a);
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        assert!(!world_ref.is_empty());
+        assert_eq!(world_ref.entity_count(), 2);
+    }
+
+    #[test]
+    fn worldref_count_with() {
+        let mut world = World::new();
+        world.spawn((Name("a"), Health(1)));
+        world.spawn(Name("b"));
+        world.spawn(Health(2));
+        let gone = world.spawn(Health(3)).id();
+        world.despawn(gone);
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        assert_eq!(world_ref.count_with::<Health>(), 2);
+        assert_eq!(world_ref.count_with::<Name>(), 2);
+        assert_eq!(world_ref.count_with::<Target>(), 0);
+    }
+
+    #[test]
+    fn worldref_count_with_includes_disabled() {
+        let mut world = World::new();
+        world.spawn((Health(1), Disabled));
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        assert_eq!(world_ref.count_with::<Health>(), world_ref.entity_count());
+        assert_eq!(world_ref.count_with::<Health>(), 1);
+    }
+
+    #[test]
+    fn worldref_find_by_name() {
+        let mut world = World::new();
+        world.spawn((Name("goblin"), Health(3)));
+        let player = world.spawn((Name("player"), Health(10))).id();
+        world.spawn(Name("chest"));
+        let gone = world.spawn(Name("ghost")).id();
+        world.despawn(gone);
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let named = |name| move |p: EntityPtr| p.get::<Name>().is_some_and(|n| n.0 == name);
+        assert_eq!(
+            world_ref.find(named("player")).map(|p| p.entity()),
+            Some(player)
+        );
+        assert!(world_ref.find(named("ghost")).is_none());
+
+        // Stops scanning at the first match
+        let mut visited = 0;
+        let found = world_ref.find(|_| {
+            visited += 1;
+            true
+        });
+        assert!(found.is_some());
+        assert_eq!(visited, 1);
+    }
+
+    #[test]
+    fn worldref_find_includes_disabled() {
+        let mut world = World::new();
+        let hidden = world.spawn((Name("hidden"), Disabled)).id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        assert_eq!(world_ref.entity_count(), 1);
+        assert_eq!(world_ref.find(|_| true).map(|p| p.entity()), Some(hidden));
+    }
+
+    #[test]
+    fn worldref_any_targets() {
+        let mut world = World::new();
+        let hero = world.spawn(Name("hero")).id();
+        let bystander = world.spawn(Name("bystander")).id();
+        world.spawn((Name("goblin"), Target(EntityHandle::new(hero))));
+        world.spawn((Name("orc"), Target(EntityHandle::new(hero))));
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let targets =
+            |victim| move |p: EntityPtr| p.get::<Target>().is_some_and(|t| t.0.entity() == victim);
+        assert!(world_ref.any(targets(hero)));
+        assert!(!world_ref.any(targets(bystander)));
+
+        // Stops before visiting all four entities
+        let mut checked = 0;
+        assert!(world_ref.any(|p| {
+            checked += 1;
+            p.has::<Target>()
+        }));
+        assert!(checked < 4);
+    }
+
+    #[test]
+    fn worldref_any_includes_disabled() {
+        let mut world = World::new();
+        world.spawn((Name("hidden"), Disabled));
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        assert!(world_ref.any(|_| true));
+    }
+
+    #[test]
+    fn worldref_validate_path() {
+        let mut world = World::new();
+        let c = world.spawn(Name("c")).id();
+        let b = world.spawn((Name("b"), Target(EntityHandle::new(c)))).id();
+        let a = world.spawn((Name("a"), Target(EntityHandle::new(b)))).id();
+        let path = [a, b, c].map(EntityHandle::new);
+
+        {
+            // SAFETY: world outlives the WorldRef usage in this block
+            let world_ref = unsafe { WorldRef::new(&world) };
+            assert!(world_ref.validate_path(&path));
+            assert!(world_ref.validate_path(&[]));
+            assert!(world_ref.validate_linked_path::<Target, _>(a, |t| t.0, &[b, c]));
+            assert!(world_ref.validate_linked_path::<Target, _>(a, |t| t.0, &[]));
+            // Wrong order
+            assert!(!world_ref.validate_linked_path::<Target, _>(a, |t| t.0, &[c, b]));
+            // Walks past the last link
+            assert!(!world_ref.validate_linked_path::<Target, _>(b, |t| t.0, &[c, a]));
+        }
+
+        // Break the chain: b now points elsewhere, and c is gone
+        let detour = world.spawn(Name("detour")).id();
+        world
+            .entity_mut(b)
+            .insert(Target(EntityHandle::new(detour)));
+        world.despawn(c);
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        assert!(!world_ref.validate_path(&path));
+        assert!(world_ref.validate_path(&path[..2]));
+        assert!(!world_ref.validate_linked_path::<Target, _>(a, |t| t.0, &[b, c]));
+        assert!(world_ref.validate_linked_path::<Target, _>(a, |t| t.0, &[b, detour]));
+        // A despawned start fails even with nothing left to check
+        assert!(!world_ref.validate_linked_path::<Target, _>(c, |t| t.0, &[]));
+    }
+
+    #[test]
+    fn entityptr_world_id() {
+        let mut world_a = World::new();
+        let mut world_b = World::new();
+        let a1 = world_a.spawn(Name("a1")).id();
+        let a2 = world_a.spawn(Name("a2")).id();
+        let b1 = world_b.spawn(Name("b1")).id();
+
+        // SAFETY: both worlds outlive the WorldRef usage in this test
+        let (ref_a, ref_b) = unsafe { (WorldRef::new(&world_a), WorldRef::new(&world_b)) };
+        let (p1, p2, p3) = (ref_a.entity(a1), ref_a.entity(a2), ref_b.entity(b1));
+
+        assert_eq!(p1.world_id(), p2.world_id());
+        assert_ne!(p1.world_id(), p3.world_id());
+        assert_eq!(p1.world_id(), std::ptr::from_ref(&world_a));
+    }
+
+    #[test]
+    fn entityptr_snapshot() {
+        let mut world = World::new();
+        let entity = world.spawn(Name("snap")).id();
+        let gone = world.spawn(Name("gone")).id();
+        world.despawn(gone);
+
+        let snapshot = {
+            // SAFETY: world outlives the WorldRef usage in this block
+            let world_ref = unsafe { WorldRef::new(&world) };
+            assert!(world_ref.entity(gone).snapshot().is_none());
+            world_ref.entity(entity).snapshot().unwrap()
+        };
+        assert!(snapshot.still_valid(&world));
+
+        world.despawn(entity);
+        assert!(!snapshot.still_valid(&world));
+    }
+
+    #[test]
+    fn worldref_resolve_path() {
+        let mut world = World::new();
+        let boss = world.spawn((Name("boss"), Health(500))).id();
+        let minion = world
+            .spawn((Name("minion"), Target(EntityHandle::new(boss))))
+            .id();
+        let squad = world
+            .spawn(OptionalTarget(Some(EntityHandle::new(minion))))
+            .id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let to_member = |p: EntityPtr| p.follow_opt::<OptionalTarget, _>(|t| t.0);
+        let to_target = |p: EntityPtr| p.follow::<Target, _>(|t| t.0);
+        let only_healthy = |p: EntityPtr| p.has::<Health>().then_some(p);
+
+        let found = world_ref.resolve_path(squad, &[to_member, to_target, only_healthy]);
+        assert_eq!(found.map(|p| p.entity()), Some(boss));
+
+        // Stops at the first failing step
+        assert!(
+            world_ref
+                .resolve_path(squad, &[to_member, only_healthy, to_target])
+                .is_none()
+        );
+        assert_eq!(world_ref.resolve_path(squad, &[]).unwrap().entity(), squad);
+    }
+
+    #[test]
+    fn entityptr_iter_ext_filters() {
+        let mut world = World::new();
+        let alice = world.spawn((Name("alice"), Health(10))).id();
+        let bob = world.spawn(Name("bob")).id();
+        let carol = world.spawn((Name("carol"), Health(5))).id();
+        let gone = world.spawn((Name("gone"), Health(1))).id();
+        let team = world
+            .spawn(Team(
+                [alice, gone, bob, carol].map(EntityHandle::new).to_vec(),
+            ))
+            .id();
+        world.despawn(gone);
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let team = world_ref.entity(team);
+        let alive: Vec<_> = team
+            .follow_many::<Team, _>(|t| &t.0)
+            .alive()
+            .map(|p| p.entity())
+            .collect();
+        assert_eq!(alive, vec![alice, bob, carol]);
+
+        let healthy: Vec<_> = team
+            .follow_many::<Team, _>(|t| &t.0)
+            .alive()
+            .with_component::<Health>()
+            .map(|p| p.entity())
+            .collect();
+        assert_eq!(healthy, vec![alice, carol]);
+    }
+
+    #[test]
+    fn sort_by_component_missing_last() {
+        let mut world = World::new();
+        let strong = world.spawn(Health(90)).id();
+        let bare_1 = world.spawn(Name("bare 1")).id();
+        let weak = world.spawn(Health(10)).id();
+        let bare_2 = world.spawn(Name("bare 2")).id();
+        let middle = world.spawn(Health(50)).id();
+        let team = world
+            .spawn(Team(
+                [strong, bare_1, weak, bare_2, middle]
+                    .map(EntityHandle::new)
+                    .to_vec(),
+            ))
+            .id();
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let mut members: Vec<_> = world_ref
+            .entity(team)
+            .follow_many::<Team, _>(|t| &t.0)
+            .collect();
+
+        sort_by_component::<Health, _, _>(&mut members, |h| h.0);
+        let order: Vec<_> = members.iter().map(|p| p.entity()).collect();
+        assert_eq!(order, vec![weak, middle, strong, bare_1, bare_2]);
+
+        sort_by_component::<Health, _, _>(&mut members, |h| std::cmp::Reverse(h.0));
+        let order: Vec<_> = members.iter().map(|p| p.entity()).collect();
+        assert_eq!(order, vec![strong, middle, weak, bare_1, bare_2]);
+
+        // The key runs once per entity holding `Health`, not once per comparison
+        let mut calls = 0;
+        sort_by_component::<Health, _, _>(&mut members, |h| {
+            calls += 1;
+            h.0
+        });
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn entityptr_get_ref2_change_detection() {
+        use bevy_ecs::change_detection::DetectChanges;
+
+        let mut world = World::new();
+        let entity = world.spawn((Name("tracked"), Health(10))).id();
+        let partial = world.spawn(Health(1)).id();
+        world.clear_trackers();
+        world.get_mut::<Health>(entity).unwrap().0 = 5;
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let (name, health) = world_ref.entity(entity).get_ref2::<Name, Health>().unwrap();
+        assert_eq!((name.0, health.0), ("tracked", 5));
+        assert!(health.is_changed());
+        assert!(!name.is_changed());
+        assert!(
+            world_ref
+                .entity(partial)
+                .get_ref2::<Name, Health>()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn worldref_entity_from_raw_u32() {
+        let mut world = World::new();
+        let entity = world.spawn(Name("raw")).id();
+        let recycled = world.spawn_empty().id();
+        world.despawn(recycled);
+        let reused = world.spawn_empty().id();
+        assert_eq!(reused.index(), recycled.index());
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let ptr = world_ref.entity_from_raw_u32(entity.index_u32()).unwrap();
+        assert_eq!(ptr.get::<Name>().unwrap().0, "raw");
+
+        // Invalid index, unallocated index, and a slot holding a later generation
+        assert!(world_ref.entity_from_raw_u32(u32::MAX).is_none());
+        assert!(world_ref.entity_from_raw_u32(10_000).is_none());
+        assert!(world_ref.entity_from_raw_u32(reused.index_u32()).is_none());
+    }
+
+    #[test]
+    fn entityptr_merge_world() {
+        let mut world_a = World::new();
+        let mut world_b = World::new();
+        let a1 = world_a.spawn(Name("a1")).id();
+        let a2 = world_a.spawn(Name("a2")).id();
+        let b1 = world_b.spawn(Name("b1")).id();
+
+        // SAFETY: both worlds outlive the WorldRef usage in this test
+        let (ref_a, ref_b) = unsafe { (WorldRef::new(&world_a), WorldRef::new(&world_b)) };
+        let (p1, p2, p3) = (ref_a.entity(a1), ref_a.entity(a2), ref_b.entity(b1));
+
+        assert_eq!(p1.merge_world(p2).map(|p| p.entity()), Some(a1));
+        assert!(p1.merge_world(p3).is_none());
+        assert!(p3.merge_world(p2).is_none());
+    }
+
+    #[test]
+    fn worldref_gather() {
+        let mut world = World::new();
+        let a = world.spawn(Health(10)).id();
+        let b = world.spawn(Name("no health")).id();
+        let c = world.spawn(Health(30)).id();
+        let gone = world.spawn(Health(99)).id();
+        world.despawn(gone);
+        let handles = [a, b, gone, c].map(EntityHandle::new);
+
+        // SAFETY: world outlives the WorldRef usage in this test
+        let world_ref = unsafe { WorldRef::new(&world) };
+        let values = world_ref.gather::<Health, _, _>(&handles, |h| h.map(|h| h.0));
+        assert_eq!(values, vec![Some(10), None, None, Some(30)]);
+
+        let total: i32 = world_ref
+            .gather::<Health, _, _>(&handles, |h| h.map_or(0, |h| h.0))
+            .into_iter()
+            .sum();
+        assert_eq!(total, 40);
+        assert!(world_ref.gather::<Health, _, _>(&[], |_| ()).is_empty());
+    }
+
+    #[test]
+    fn entityptr_get_or_last() {
+        #[derive(Component, Clone, Debug, PartialEq)]
+        struct Position(i32, i32);
+
+        let mut world = World::new();
+        let entity = world.spawn(Position(1, 2)).id();
+        let mut last = None;
+
+        // SAFETY: world outlives each WorldRef usage in this test
+        let read = |world: &World, last: &mut Option<Position>| unsafe {
+            WorldRef::new(world).entity(entity).get_or_last(last)
+        };
+
+        assert_eq!(read(&world, &mut last), Some(Position(1, 2)));
+        assert_eq!(last, Some(Position(1, 2)));
+
+        world.entity_mut(entity).insert(Position(3, 4));
+        assert_eq!(read(&world, &mut last), Some(Position(3, 4)));
+
+        // Component removed: the cached value bridges the gap
+        world.entity_mut(entity).remove::<Position>();
+        assert_eq!(read(&world, &mut last), Some(Position(3, 4)));
+
+        // Entity despawned: still the cached value
+        world.despawn(entity);
+        assert_eq!(read(&world, &mut last), Some(Position(3, 4)));
+
+        // Nothing ever seen
+        let mut empty = None;
+        assert_eq!(read(&world, &mut empty), None);
+    }
 }
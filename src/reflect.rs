@@ -0,0 +1,102 @@
+//! Reflective component access (requires the `reflect` feature).
+
+use bevy_reflect::{Reflect, ReflectFromPtr, TypeRegistry};
+
+use crate::ptr::EntityPtr;
+
+impl EntityPtr {
+    /// Calls `f` with a reflected view of each of this entity's components.
+    ///
+    /// Components are resolved through the `ReflectFromPtr` data in `registry`;
+    /// components whose type is not registered (or lacks `ReflectFromPtr`) are
+    /// skipped. Does nothing if the entity has been despawned. Useful for generic
+    /// inspectors that print every value of an entity during traversal.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_entity_ptr::WorldExt;
+    /// use bevy_reflect::{Reflect, TypeRegistry};
+    ///
+    /// #[derive(Component, Reflect)]
+    /// struct Health(i32);
+    ///
+    /// let mut registry = TypeRegistry::default();
+    /// registry.register::<Health>();
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.spawn(Health(100)).id();
+    ///
+    /// let mut values = Vec::new();
+    /// world
+    ///     .entity_ptr(entity)
+    ///     .for_each_component_reflect(&registry, |value| values.push(format!("{value:?}")));
+    /// assert_eq!(values.len(), 1);
+    /// assert!(values[0].contains("100"));
+    /// ```
+    pub fn for_each_component_reflect(
+        self,
+        registry: &TypeRegistry,
+        mut f: impl FnMut(&dyn Reflect),
+    ) {
+        let world = self.world();
+        let Ok(entity_ref) = world.get_entity(self.entity()) else {
+            return;
+        };
+
+        for &id in entity_ref.archetype().components() {
+            let Some(reflect_from_ptr) = world
+                .components()
+                .get_info(id)
+                .and_then(|info| info.type_id())
+                .and_then(|type_id| registry.get_type_data::<ReflectFromPtr>(type_id))
+            else {
+                continue;
+            };
+            let Ok(ptr) = entity_ref.get_by_id(id) else {
+                continue;
+            };
+            // SAFETY: `reflect_from_ptr` was looked up by the component's own TypeId,
+            // so `ptr` points to a value of the type it was registered for.
+            f(unsafe { reflect_from_ptr.as_reflect(ptr) });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::WorldExt;
+    use bevy_ecs::component::Component;
+    use bevy_ecs::world::World;
+    use bevy_reflect::{Reflect, TypeRegistry};
+
+    #[derive(Component, Reflect)]
+    struct Health(i32);
+
+    #[derive(Component)]
+    struct Unregistered;
+
+    #[test]
+    fn for_each_component_reflect_skips_unregistered() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Health>();
+
+        let mut world = World::new();
+        let entity = world.spawn((Health(42), Unregistered)).id();
+
+        let mut seen = Vec::new();
+        world
+            .entity_ptr(entity)
+            .for_each_component_reflect(&registry, |value| {
+                seen.push(value.downcast_ref::<Health>().map(|h| h.0));
+            });
+        assert_eq!(seen, vec![Some(42)]);
+
+        world.despawn(entity);
+        let mut calls = 0;
+        world
+            .entity_ptr(entity)
+            .for_each_component_reflect(&registry, |_| calls += 1);
+        assert_eq!(calls, 0);
+    }
+}
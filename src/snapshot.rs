@@ -0,0 +1,87 @@
+//! Owned component snapshots - cloning component values out of the world.
+//!
+//! `EntityPtr` reads through a reference to the `World`, so its data is only valid
+//! while that World is. `CloneComponents` copies selected values into an owned tuple
+//! that holds no world reference.
+
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::world::World;
+
+/// A set of component types that `snapshot_components()` clones out of an entity.
+///
+/// Implemented for tuples of up to four `Component + Clone` types, e.g. `(Health,)`
+/// or `(Health, Armor)`. The resulting tuple is owned and free of world references,
+/// so it can be kept after the World changes or is dropped.
+pub trait CloneComponents: Sized {
+    /// Clones every component in the set from `entity`.
+    ///
+    /// Returns `None` unless the entity exists and has all of them.
+    fn clone_components(world: &World, entity: Entity) -> Option<Self>;
+}
+
+macro_rules! impl_clone_components {
+    ($($name:ident),+) => {
+        impl<$($name: Component + Clone),+> CloneComponents for ($($name,)+) {
+            fn clone_components(world: &World, entity: Entity) -> Option<Self> {
+                Some(($(world.get::<$name>(entity)?.clone(),)+))
+            }
+        }
+    };
+}
+
+impl_clone_components!(A);
+impl_clone_components!(A, B);
+impl_clone_components!(A, B, C);
+impl_clone_components!(A, B, C, D);
+
+#[cfg(test)]
+mod tests {
+    use crate::{EntityHandle, WorldExt};
+    use bevy_ecs::component::Component;
+    use bevy_ecs::world::World;
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    struct Health(i32);
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    struct Armor(u8);
+
+    #[test]
+    fn snapshot_components_crosses_threads() {
+        let mut world = World::new();
+        let full = world.spawn((Health(30), Armor(4))).id();
+        let partial = world.spawn(Health(5)).id();
+
+        let snap = world
+            .entity_ptr(full)
+            .snapshot_components::<(Health, Armor)>();
+        assert_eq!(snap, Some((Health(30), Armor(4))));
+        assert!(
+            world
+                .entity_ptr(partial)
+                .snapshot_components::<(Health, Armor)>()
+                .is_none()
+        );
+
+        let bound = EntityHandle::new(partial).bind(&world);
+        assert_eq!(bound.snapshot_components::<(Health,)>(), Some((Health(5),)));
+
+        // The snapshot is owned, so it can move to another thread
+        let total = std::thread::spawn(move || {
+            let (health, armor) = snap.unwrap();
+            health.0 + i32::from(armor.0)
+        })
+        .join()
+        .unwrap();
+        assert_eq!(total, 34);
+
+        world.despawn(full);
+        assert!(
+            world
+                .entity_ptr(full)
+                .snapshot_components::<(Health,)>()
+                .is_none()
+        );
+    }
+}
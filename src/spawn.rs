@@ -0,0 +1,130 @@
+//! Construction helpers for building handle-based hierarchies.
+//!
+//! The rest of the crate is read-only; these helpers only exist to cut the
+//! boilerplate of the spawn-then-record-handle pattern when setting up scenes.
+
+use bevy_ecs::bundle::Bundle;
+use bevy_ecs::system::Commands;
+use bevy_ecs::world::World;
+
+use crate::handle::EntityHandle;
+
+/// Extension trait for spawning an entity and recording its handle in one step.
+///
+/// Implemented for `World` and `Commands`.
+///
+/// # Example
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_entity_ptr::{EntityHandle, SpawnChildExt};
+///
+/// #[derive(Component)]
+/// struct Name(&'static str);
+///
+/// #[derive(Component)]
+/// struct Children(Vec<EntityHandle>);
+///
+/// let mut world = World::new();
+/// let mut children = Vec::new();
+/// world.spawn_child(Name("left"), &mut children);
+/// world.spawn_child(Name("right"), &mut children);
+/// let root = world.spawn((Name("root"), Children(children))).id();
+///
+/// assert_eq!(world.get::<Children>(root).unwrap().0.len(), 2);
+/// ```
+pub trait SpawnChildExt {
+    /// Spawns `bundle` and appends the new entity's handle to `parent_children`.
+    ///
+    /// Returns the handle of the spawned entity.
+    fn spawn_child(
+        &mut self,
+        bundle: impl Bundle,
+        parent_children: &mut Vec<EntityHandle>,
+    ) -> EntityHandle;
+}
+
+impl SpawnChildExt for World {
+    fn spawn_child(
+        &mut self,
+        bundle: impl Bundle,
+        parent_children: &mut Vec<EntityHandle>,
+    ) -> EntityHandle {
+        let handle = EntityHandle::new(self.spawn(bundle).id());
+        parent_children.push(handle);
+        handle
+    }
+}
+
+impl SpawnChildExt for Commands<'_, '_> {
+    fn spawn_child(
+        &mut self,
+        bundle: impl Bundle,
+        parent_children: &mut Vec<EntityHandle>,
+    ) -> EntityHandle {
+        let handle = EntityHandle::new(self.spawn(bundle).id());
+        parent_children.push(handle);
+        handle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WorldExt;
+    use bevy_ecs::component::Component;
+
+    #[derive(Component)]
+    struct Name(&'static str);
+
+    #[derive(Component)]
+    struct Children(Vec<EntityHandle>);
+
+    #[test]
+    fn world_spawn_child_builds_tree() {
+        let mut world = World::new();
+
+        let mut branch_children = Vec::new();
+        let leaf = world.spawn_child(Name("leaf"), &mut branch_children);
+
+        let mut root_children = Vec::new();
+        let branch = world.spawn_child(
+            (Name("branch"), Children(branch_children)),
+            &mut root_children,
+        );
+        world.spawn_child(Name("sibling"), &mut root_children);
+        let root = world.spawn((Name("root"), Children(root_children))).id();
+
+        let root = world.bind_entity(root);
+        let kids = &root.get::<Children>().unwrap().0;
+        assert_eq!(kids.len(), 2);
+        assert_eq!(kids[0], branch);
+
+        let names: Vec<_> = kids
+            .iter()
+            .map(|h| h.get::<Name>(&world).unwrap().0)
+            .collect();
+        assert_eq!(names, vec!["branch", "sibling"]);
+
+        let grandchild = branch.bind(&world).get::<Children>().unwrap().0[0];
+        assert_eq!(grandchild, leaf);
+        assert_eq!(leaf.get::<Name>(&world).unwrap().0, "leaf");
+    }
+
+    #[test]
+    fn commands_spawn_child_reserves_handle() {
+        use bevy_ecs::world::CommandQueue;
+
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut children = Vec::new();
+
+        let child = {
+            let mut commands = Commands::new(&mut queue, &world);
+            commands.spawn_child(Name("queued"), &mut children)
+        };
+        assert_eq!(children, vec![child]);
+
+        queue.apply(&mut world);
+        assert_eq!(child.get::<Name>(&world).unwrap().0, "queued");
+    }
+}
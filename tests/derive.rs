@@ -0,0 +1,77 @@
+//! Tests for the `derive` feature's `HasChildren` / `HasParent` derive macros.
+
+use bevy_ecs::prelude::*;
+use bevy_entity_ptr::{EntityHandle, HasChildren, HasParent, WorldExt};
+
+#[derive(Component)]
+struct Name(&'static str);
+
+#[derive(Component, HasChildren)]
+struct Kids(Vec<EntityHandle>);
+
+#[derive(Component, HasParent)]
+struct Up(Option<EntityHandle>);
+
+#[derive(Component, HasChildren, HasParent)]
+struct Node {
+    #[allow(dead_code)]
+    label: &'static str,
+    #[parent]
+    parent: EntityHandle,
+    #[children]
+    children: Vec<EntityHandle>,
+}
+
+#[test]
+fn derived_single_field_impls() {
+    let mut world = World::new();
+    let a = world.spawn(Name("a")).id();
+    let b = world.spawn(Name("b")).id();
+    let root = world
+        .spawn((
+            Name("root"),
+            Kids(vec![EntityHandle::new(a), EntityHandle::new(b)]),
+        ))
+        .id();
+    world
+        .entity_mut(a)
+        .insert(Up(Some(EntityHandle::new(root))));
+    world.entity_mut(root).insert(Up(None));
+
+    let names: Vec<_> = world
+        .entity_ptr(root)
+        .nav_many()
+        .children::<Kids>()
+        .map(|c| c.get::<Name>().unwrap().0)
+        .collect();
+    assert_eq!(names, vec!["a", "b"]);
+
+    let parent = world.bind_entity(a).nav().parent::<Up>().unwrap();
+    assert_eq!(parent.entity(), root);
+    assert!(world.entity_ptr(root).nav().parent::<Up>().is_none());
+}
+
+#[test]
+fn derived_marked_field_impls() {
+    let mut world = World::new();
+    let top = world.spawn(Name("top")).id();
+    let leaf = world.spawn(Name("leaf")).id();
+    let middle = world
+        .spawn(Node {
+            label: "middle",
+            parent: EntityHandle::new(top),
+            children: vec![EntityHandle::new(leaf)],
+        })
+        .id();
+
+    let node = world.get::<Node>(middle).unwrap();
+    assert_eq!(node.parent_handle(), Some(EntityHandle::new(top)));
+    assert_eq!(node.children_handles(), &[EntityHandle::new(leaf)]);
+
+    let ptr = world.entity_ptr(middle);
+    assert_eq!(ptr.nav().parent::<Node>().unwrap().entity(), top);
+    assert_eq!(
+        ptr.nav_many().children::<Node>().next().unwrap().entity(),
+        leaf
+    );
+}